@@ -18,10 +18,11 @@
 extern crate arrow;
 extern crate datafusion;
 
-use arrow::array::{Float64Array, Int32Array, StringArray};
-
+use datafusion::dataframe::DataFrame;
 use datafusion::error::Result;
 use datafusion::execution::context::ExecutionContext;
+use datafusion::execution::physical_plan::find_parquet_exec;
+use std::sync::atomic::Ordering;
 
 /// This example demonstrates executing a simple query against an Arrow data source (Parquet) and
 /// fetching results
@@ -42,48 +43,28 @@ fn main() -> Result<()> {
     let sql = "SELECT int_col, double_col, CAST(date_string_col as VARCHAR) FROM alltypes_plain WHERE id > 1 AND tinyint_col < double_col";
 
     // create the query plan
-    let plan = ctx.create_logical_plan(&sql)?;
+    let plan = ctx.create_logical_plan(sql)?;
     let plan = ctx.optimize(&plan)?;
     let plan = ctx.create_physical_plan(&plan, 1024 * 1024)?;
 
-    // execute the query
-    let results = ctx.collect(plan.as_ref())?;
+    // execute the query, pulling one RecordBatch at a time rather than
+    // buffering the whole result set in memory, and print each as it
+    // arrives
+    for batch in ctx.execute_stream(plan.clone())? {
+        DataFrame::new(vec![batch?]).show()?;
+    }
 
-    // iterate over the results
-    results.iter().for_each(|batch| {
+    // For this query (it has a WHERE clause) the plan is wrapped as
+    // `ProjectionExec(FilterExec(ParquetExec))`, so look past those wrapper
+    // operators rather than downcasting `plan` itself.
+    if let Some(parquet_exec) = find_parquet_exec(&plan) {
+        let metrics = parquet_exec.metrics();
         println!(
-            "RecordBatch has {} rows and {} columns",
-            batch.num_rows(),
-            batch.num_columns()
+            "Pruned {} of {} row groups using Parquet statistics",
+            metrics.row_groups_pruned.load(Ordering::Relaxed),
+            metrics.row_groups_total.load(Ordering::Relaxed)
         );
-
-        let int = batch
-            .column(0)
-            .as_any()
-            .downcast_ref::<Int32Array>()
-            .unwrap();
-
-        let double = batch
-            .column(1)
-            .as_any()
-            .downcast_ref::<Float64Array>()
-            .unwrap();
-
-        let date = batch
-            .column(2)
-            .as_any()
-            .downcast_ref::<StringArray>()
-            .unwrap();
-
-        for i in 0..batch.num_rows() {
-            println!(
-                "Date: {}, Int: {}, Double: {}",
-                date.value(i),
-                int.value(i),
-                double.value(i)
-            );
-        }
-    });
+    }
 
     Ok(())
 }
@@ -0,0 +1,159 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+extern crate datafusion;
+
+use datafusion::dataframe::{DataFrame, OutputFormat};
+use datafusion::execution::context::ExecutionContext;
+
+use std::io::{self, BufRead, Write};
+use std::time::Instant;
+
+/// An interactive SQL shell over `ExecutionContext`, for ad-hoc exploration
+/// without writing a Rust program. Statements are read from stdin, run
+/// through `ExecutionContext::sql` (the same create_logical_plan ->
+/// optimize -> create_physical_plan -> collect pipeline used elsewhere),
+/// and printed as a table. A statement may span multiple lines; it ends at
+/// the first line whose trailing `;` is seen.
+///
+/// Lines starting with `\` are meta-commands rather than SQL:
+///
+///   \register_parquet <name> <path>   register a single Parquet file
+///   \tables                           list registered tables and their schemas
+///   \timing                           toggle printing each query's elapsed time
+///   \format table|csv                 choose how result sets are printed
+///   \quit                             exit the session
+///
+/// Parse and execution errors are printed and the session continues.
+fn main() {
+    let mut ctx = ExecutionContext::new();
+    let mut timing = false;
+    let mut format = OutputFormat::Table;
+    let mut buffer = String::new();
+
+    let stdin = io::stdin();
+    loop {
+        print_prompt(&buffer);
+
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => break, // EOF
+            Ok(_) => {}
+            Err(e) => {
+                println!("error reading stdin: {}", e);
+                break;
+            }
+        }
+        let line = line.trim_end_matches('\n');
+
+        if buffer.is_empty() {
+            if let Some(command) = line.trim().strip_prefix('\\') {
+                if !run_meta_command(&mut ctx, &mut timing, &mut format, command) {
+                    break;
+                }
+                continue;
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+        }
+
+        buffer.push_str(line);
+        if line.trim_end().ends_with(';') {
+            let sql = buffer.trim().trim_end_matches(';').to_string();
+            buffer.clear();
+            run_statement(&mut ctx, &sql, timing, format);
+        } else {
+            buffer.push('\n');
+        }
+    }
+}
+
+fn print_prompt(buffer: &str) {
+    print!("{}", if buffer.is_empty() { "> " } else { ". " });
+    let _ = io::stdout().flush();
+}
+
+/// Runs a `\`-prefixed meta-command. Returns `false` if the session should
+/// exit.
+fn run_meta_command(
+    ctx: &mut ExecutionContext,
+    timing: &mut bool,
+    format: &mut OutputFormat,
+    command: &str,
+) -> bool {
+    let mut parts = command.trim().splitn(3, char::is_whitespace);
+    match parts.next().unwrap_or("") {
+        "quit" | "q" => return false,
+        "register_parquet" => match (parts.next(), parts.next()) {
+            (Some(name), Some(path)) => match ctx.register_parquet(name, path) {
+                Ok(()) => println!("registered '{}' -> {}", name, path),
+                Err(e) => println!("error: {}", e),
+            },
+            _ => println!("usage: \\register_parquet <name> <path>"),
+        },
+        "tables" => {
+            for name in ctx.table_names() {
+                let schema = ctx.table(&name).unwrap().schema();
+                let columns: Vec<String> = schema
+                    .fields()
+                    .iter()
+                    .map(|f| format!("{}: {:?}", f.name(), f.data_type()))
+                    .collect();
+                println!("{} ({})", name, columns.join(", "));
+            }
+        }
+        "timing" => {
+            *timing = !*timing;
+            println!("timing is {}", if *timing { "on" } else { "off" });
+        }
+        "format" => match parts.next() {
+            Some("table") => {
+                *format = OutputFormat::Table;
+                println!("output format is table");
+            }
+            Some("csv") => {
+                *format = OutputFormat::Csv;
+                println!("output format is csv");
+            }
+            _ => println!("usage: \\format table|csv"),
+        },
+        other => println!("unknown command: \\{}", other),
+    }
+    true
+}
+
+fn run_statement(ctx: &mut ExecutionContext, sql: &str, timing: bool, format: OutputFormat) {
+    if sql.trim().is_empty() {
+        return;
+    }
+
+    let start = Instant::now();
+    match ctx.sql(sql) {
+        Ok(batches) => {
+            if !batches.is_empty() {
+                if let Err(e) = DataFrame::new(batches).show_as(format) {
+                    println!("error: {}", e);
+                }
+            }
+            if timing {
+                println!("({:?})", start.elapsed());
+            }
+        }
+        Err(e) => println!("error: {}", e),
+    }
+}
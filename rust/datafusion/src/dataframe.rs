@@ -0,0 +1,299 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A thin wrapper around a collected result set that knows how to print
+//! itself as an aligned ASCII table, so callers don't have to downcast each
+//! column to print it.
+
+use arrow::array::Array;
+use arrow::datatypes::DataType;
+use arrow::record_batch::RecordBatch;
+
+use crate::error::Result;
+
+/// A query result: the `RecordBatch`es produced by `ExecutionContext::collect`,
+/// together with `show()`/`show_limit()` helpers for ad-hoc inspection.
+pub struct DataFrame {
+    batches: Vec<RecordBatch>,
+}
+
+impl DataFrame {
+    pub fn new(batches: Vec<RecordBatch>) -> Self {
+        DataFrame { batches }
+    }
+
+    pub fn batches(&self) -> &[RecordBatch] {
+        &self.batches
+    }
+
+    /// Prints every row as an aligned ASCII table.
+    pub fn show(&self) -> Result<()> {
+        self.show_limit(usize::MAX)
+    }
+
+    /// Prints at most `limit` rows as an aligned ASCII table.
+    pub fn show_limit(&self, limit: usize) -> Result<()> {
+        print!("{}", render(&self.batches, limit)?);
+        Ok(())
+    }
+
+    /// Prints every row using the given `OutputFormat`.
+    pub fn show_as(&self, format: OutputFormat) -> Result<()> {
+        match format {
+            OutputFormat::Table => self.show(),
+            OutputFormat::Csv => {
+                print!("{}", render_csv(&self.batches)?);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// How a `DataFrame` should be printed; see `DataFrame::show_as`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Table,
+    Csv,
+}
+
+/// Renders `batches` (stopping after `limit` rows) as an aligned ASCII
+/// table, e.g.:
+///
+/// ```text
+/// +----+-------+
+/// | id | name  |
+/// +----+-------+
+/// | 1  | alice |
+/// | 2  |       |
+/// +----+-------+
+/// ```
+fn render(batches: &[RecordBatch], limit: usize) -> Result<String> {
+    let mut out = String::new();
+    let schema = match batches.first() {
+        Some(b) => b.schema(),
+        None => return Ok(out),
+    };
+
+    let headers: Vec<String> = schema.fields().iter().map(|f| f.name().clone()).collect();
+    let mut rows: Vec<Vec<String>> = vec![];
+    'batches: for batch in batches {
+        for row in 0..batch.num_rows() {
+            if rows.len() >= limit {
+                break 'batches;
+            }
+            let cells = (0..batch.num_columns())
+                .map(|col| cell_to_string(batch.column(col).as_ref(), row))
+                .collect();
+            rows.push(cells);
+        }
+    }
+
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for row in &rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.len());
+        }
+    }
+
+    write_separator(&mut out, &widths);
+    write_row(&mut out, &headers, &widths);
+    write_separator(&mut out, &widths);
+    for row in &rows {
+        write_row(&mut out, row, &widths);
+    }
+    write_separator(&mut out, &widths);
+
+    Ok(out)
+}
+
+/// Renders `batches` as comma-separated values, one header row followed by
+/// one row per record; cells are not quoted or escaped.
+fn render_csv(batches: &[RecordBatch]) -> Result<String> {
+    let mut out = String::new();
+    let schema = match batches.first() {
+        Some(b) => b.schema(),
+        None => return Ok(out),
+    };
+
+    let headers: Vec<String> = schema.fields().iter().map(|f| f.name().clone()).collect();
+    out.push_str(&headers.join(","));
+    out.push('\n');
+
+    for batch in batches {
+        for row in 0..batch.num_rows() {
+            let cells: Vec<String> = (0..batch.num_columns())
+                .map(|col| cell_to_string(batch.column(col).as_ref(), row))
+                .collect();
+            out.push_str(&cells.join(","));
+            out.push('\n');
+        }
+    }
+
+    Ok(out)
+}
+
+fn write_separator(out: &mut String, widths: &[usize]) {
+    for w in widths {
+        out.push('+');
+        out.push_str(&"-".repeat(w + 2));
+    }
+    out.push_str("+\n");
+}
+
+fn write_row(out: &mut String, cells: &[String], widths: &[usize]) {
+    for (cell, w) in cells.iter().zip(widths) {
+        out.push_str(&format!("| {:<width$} ", cell, width = w));
+    }
+    out.push_str("|\n");
+}
+
+/// Formats a single cell without requiring the caller to downcast. Nulls
+/// render as an empty string; Boolean, signed and unsigned integer,
+/// Float32/Float64 and Utf8 columns are formatted by value. Every other
+/// type (dates, timestamps, lists, ...) renders as `<unsupported: TYPE>`.
+fn cell_to_string(array: &dyn Array, row: usize) -> String {
+    if array.is_null(row) {
+        return String::new();
+    }
+
+    macro_rules! fmt_primitive {
+        ($ty:ty) => {{
+            let array = array
+                .as_any()
+                .downcast_ref::<arrow::array::PrimitiveArray<$ty>>()
+                .unwrap();
+            format!("{}", array.value(row))
+        }};
+    }
+
+    use arrow::datatypes::{
+        Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
+        UInt32Type, UInt64Type, UInt8Type,
+    };
+
+    match array.data_type() {
+        DataType::Boolean => {
+            let array = array.as_any().downcast_ref::<arrow::array::BooleanArray>().unwrap();
+            format!("{}", array.value(row))
+        }
+        DataType::Int8 => fmt_primitive!(Int8Type),
+        DataType::Int16 => fmt_primitive!(Int16Type),
+        DataType::Int32 => fmt_primitive!(Int32Type),
+        DataType::Int64 => fmt_primitive!(Int64Type),
+        DataType::UInt8 => fmt_primitive!(UInt8Type),
+        DataType::UInt16 => fmt_primitive!(UInt16Type),
+        DataType::UInt32 => fmt_primitive!(UInt32Type),
+        DataType::UInt64 => fmt_primitive!(UInt64Type),
+        DataType::Float32 => fmt_primitive!(Float32Type),
+        DataType::Float64 => fmt_primitive!(Float64Type),
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<arrow::array::StringArray>().unwrap();
+            array.value(row).to_string()
+        }
+        other => format!("<unsupported: {:?}>", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{BooleanArray, Int64Array, StringArray};
+    use arrow::datatypes::{Field, Schema};
+    use std::sync::Arc;
+
+    #[test]
+    fn cell_to_string_renders_null_as_empty() {
+        let array = Int64Array::from(vec![Some(1), None]);
+        assert_eq!(cell_to_string(&array, 1), "");
+    }
+
+    #[test]
+    fn cell_to_string_renders_unsupported_type_fallback() {
+        let array = arrow::array::ListArray::from(arrow::array::ArrayData::new_empty(
+            &DataType::List(Box::new(Field::new("item", DataType::Int64, true))),
+        ));
+        assert!(cell_to_string(&array, 0).starts_with("<unsupported: List("));
+    }
+
+    #[test]
+    fn render_shows_null_cell_as_blank() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("alice"), None])),
+            ],
+        )
+        .unwrap();
+
+        let out = render(&[batch], usize::MAX).unwrap();
+
+        assert!(out.contains("| 1  | alice |\n"));
+        assert!(out.contains("| 2  |       |\n"));
+    }
+
+    #[test]
+    fn render_respects_limit_across_batch_boundaries() {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        let batch1 = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int64Array::from(vec![1, 2]))],
+        )
+        .unwrap();
+        let batch2 = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Int64Array::from(vec![3, 4]))],
+        )
+        .unwrap();
+
+        let out = render(&[batch1, batch2], 3).unwrap();
+
+        assert!(out.contains("| 1  |\n"));
+        assert!(out.contains("| 2  |\n"));
+        assert!(out.contains("| 3  |\n"));
+        assert!(!out.contains("| 4  |\n"));
+    }
+
+    #[test]
+    fn render_returns_empty_string_for_no_batches() {
+        assert_eq!(render(&[], usize::MAX).unwrap(), "");
+    }
+
+    #[test]
+    fn render_csv_joins_rows_with_commas_and_no_quoting() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("flag", DataType::Boolean, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(BooleanArray::from(vec![true, false])),
+            ],
+        )
+        .unwrap();
+
+        let out = render_csv(&[batch]).unwrap();
+
+        assert_eq!(out, "id,flag\n1,true\n2,false\n");
+    }
+}
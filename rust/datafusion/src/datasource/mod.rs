@@ -0,0 +1,60 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Table providers: the bridge between a registered data source and the
+//! physical plans that scan it.
+
+pub mod object_store;
+pub mod parquet;
+pub mod partitioned_parquet;
+
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+
+use crate::error::Result;
+use crate::execution::physical_plan::ExecutionPlan;
+use crate::logicalplan::{Expr, ScalarValue};
+
+/// One file making up a (possibly partitioned) table, together with the
+/// constant partition column values implied by its location, e.g. a file
+/// under `year=2020/month=03/` carries `[Int64(2020), Int64(3)]`.
+#[derive(Debug, Clone)]
+pub struct PartitionedFile {
+    pub path: String,
+    pub partition_values: Vec<ScalarValue>,
+}
+
+/// A table that can be registered with an `ExecutionContext` and scanned by
+/// a query.
+pub trait TableProvider {
+    /// The schema of this table, including any synthesized partition
+    /// columns.
+    fn schema(&self) -> SchemaRef;
+
+    /// Build a physical plan that scans the table, optionally pushing down
+    /// a column projection and a set of filter predicates that the provider
+    /// may use to prune work (e.g. whole row groups or partitions) but is
+    /// not required to apply exhaustively; downstream operators still
+    /// re-check every predicate.
+    fn scan(
+        &self,
+        projection: &Option<Vec<usize>>,
+        batch_size: usize,
+        filters: &[Expr],
+    ) -> Result<Arc<dyn ExecutionPlan>>;
+}
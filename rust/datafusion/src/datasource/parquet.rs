@@ -0,0 +1,83 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `TableProvider` implementation backed by one or more Parquet files,
+//! read through a pluggable `ObjectReaderFactory` so the files don't have
+//! to live on the local filesystem.
+
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+use parquet::arrow::{ArrowReader, ParquetFileArrowReader};
+use parquet::file::reader::SerializedFileReader;
+
+use crate::datasource::object_store::{ObjectReaderAdapter, ObjectReaderFactory};
+use crate::datasource::TableProvider;
+use crate::error::{ExecutionError, Result};
+use crate::execution::physical_plan::parquet::ParquetExec;
+use crate::execution::physical_plan::ExecutionPlan;
+use crate::logicalplan::Expr;
+
+pub struct ParquetTable {
+    uris: Vec<String>,
+    schema: SchemaRef,
+    reader_factory: Arc<dyn ObjectReaderFactory>,
+}
+
+impl ParquetTable {
+    /// Open `uri` through `reader_factory`, inferring the table schema from
+    /// the Parquet footer.
+    pub fn try_new(uri: &str, reader_factory: Arc<dyn ObjectReaderFactory>) -> Result<Self> {
+        let object_reader = reader_factory.create_reader(uri)?;
+        let chunk_reader = ObjectReaderAdapter(object_reader);
+        let file_reader = Arc::new(SerializedFileReader::new(chunk_reader).map_err(|e| {
+            ExecutionError::General(format!("failed to open {}: {}", uri, e))
+        })?);
+        let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
+        let schema = Arc::new(arrow_reader.get_schema()?);
+        Ok(ParquetTable {
+            uris: vec![uri.to_string()],
+            schema,
+            reader_factory,
+        })
+    }
+}
+
+impl TableProvider for ParquetTable {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn scan(
+        &self,
+        projection: &Option<Vec<usize>>,
+        batch_size: usize,
+        filters: &[Expr],
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let projection = projection
+            .clone()
+            .unwrap_or_else(|| (0..self.schema.fields().len()).collect());
+        Ok(Arc::new(ParquetExec::new(
+            self.uris.clone(),
+            self.schema.clone(),
+            projection,
+            batch_size,
+            filters.to_vec(),
+            self.reader_factory.clone(),
+        )))
+    }
+}
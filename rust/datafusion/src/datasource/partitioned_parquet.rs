@@ -0,0 +1,361 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `TableProvider` for a directory of Parquet files laid out Hive-style,
+//! e.g. `LOCATION '/data' PARTITIONED BY (year INT, month INT)` discovering
+//! files under `year=2020/month=03/...`.
+
+use std::fs::{self, File};
+use std::path::Path;
+use std::sync::Arc;
+
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef};
+use parquet::arrow::{ArrowReader, ParquetFileArrowReader};
+use parquet::file::reader::SerializedFileReader;
+
+use crate::datasource::object_store::LocalFileObjectReaderFactory;
+use crate::datasource::{PartitionedFile, TableProvider};
+use crate::error::{ExecutionError, Result};
+use crate::execution::physical_plan::parquet::ParquetExec;
+use crate::execution::physical_plan::pruning::partition_prunes_file;
+use crate::execution::physical_plan::ExecutionPlan;
+use crate::logicalplan::{Expr, ScalarValue};
+
+pub struct PartitionedParquetTable {
+    file_schema: SchemaRef,
+    partition_columns: Vec<Field>,
+    schema: SchemaRef,
+    files: Vec<PartitionedFile>,
+}
+
+impl PartitionedParquetTable {
+    /// Walks `location` looking for `key=value` directory segments matching
+    /// `partition_columns`, and infers the non-partition schema from the
+    /// first Parquet file found.
+    pub fn try_new(location: &str, partition_columns: Vec<(String, DataType)>) -> Result<Self> {
+        let mut files = vec![];
+        discover_files(Path::new(location), &partition_columns, &mut vec![], &mut files)?;
+        if files.is_empty() {
+            return Err(ExecutionError::General(format!(
+                "no Parquet files found under {}",
+                location
+            )));
+        }
+
+        let file = File::open(&files[0].path)?;
+        let file_reader = Arc::new(SerializedFileReader::new(file).map_err(|e| {
+            ExecutionError::General(format!("failed to open {}: {}", files[0].path, e))
+        })?);
+        let mut arrow_reader = ParquetFileArrowReader::new(file_reader);
+        let file_schema = Arc::new(arrow_reader.get_schema()?);
+
+        let partition_fields: Vec<Field> = partition_columns
+            .iter()
+            .map(|(name, data_type)| Field::new(name, widen_partition_type(data_type), false))
+            .collect();
+
+        let mut all_fields = file_schema.fields().clone();
+        all_fields.extend(partition_fields.iter().cloned());
+        let schema = Arc::new(Schema::new(all_fields));
+
+        Ok(PartitionedParquetTable {
+            file_schema,
+            partition_columns: partition_fields,
+            schema,
+            files,
+        })
+    }
+}
+
+impl TableProvider for PartitionedParquetTable {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn scan(
+        &self,
+        projection: &Option<Vec<usize>>,
+        batch_size: usize,
+        filters: &[Expr],
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let projection = projection
+            .clone()
+            .unwrap_or_else(|| (0..self.schema.fields().len()).collect());
+
+        // Partition pruning: a file can be skipped outright if its constant
+        // partition values don't satisfy a filter on a partition column.
+        let files: Vec<PartitionedFile> = self
+            .files
+            .iter()
+            .filter(|f| !partition_prunes_file(filters, &self.partition_columns, &f.partition_values))
+            .cloned()
+            .collect();
+
+        Ok(Arc::new(ParquetExec::with_partitioned_files(
+            files,
+            self.file_schema.clone(),
+            self.partition_columns.clone(),
+            projection,
+            batch_size,
+            filters.to_vec(),
+            Arc::new(LocalFileObjectReaderFactory),
+        )))
+    }
+}
+
+fn discover_files(
+    dir: &Path,
+    partition_columns: &[(String, DataType)],
+    values_so_far: &mut Vec<ScalarValue>,
+    out: &mut Vec<PartitionedFile>,
+) -> Result<()> {
+    let depth = values_so_far.len();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.is_dir() {
+            if depth >= partition_columns.len() {
+                continue;
+            }
+            let (name, data_type) = &partition_columns[depth];
+            let dir_name = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default();
+            let value = parse_partition_value(dir_name, name, data_type)?;
+            values_so_far.push(value);
+            discover_files(&path, partition_columns, values_so_far, out)?;
+            values_so_far.pop();
+        } else if path.extension().map(|e| e == "parquet").unwrap_or(false) {
+            if depth < partition_columns.len() {
+                return Err(ExecutionError::General(format!(
+                    "{} is not under a '{}=<value>' partition directory",
+                    path_to_string(&path),
+                    partition_columns[depth].0
+                )));
+            }
+            out.push(PartitionedFile {
+                path: path_to_string(&path),
+                partition_values: values_so_far.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Parses a `key=value` directory segment, e.g. `year=2020`, into the
+/// declared partition column's value.
+fn parse_partition_value(segment: &str, name: &str, data_type: &DataType) -> Result<ScalarValue> {
+    let (key, value) = segment.split_once('=').ok_or_else(|| {
+        ExecutionError::General(format!(
+            "expected Hive-style partition directory '{}=<value>', found '{}'",
+            name, segment
+        ))
+    })?;
+    if key != name {
+        return Err(ExecutionError::General(format!(
+            "expected partition column '{}' at this directory depth, found '{}'",
+            name, key
+        )));
+    }
+    match data_type {
+        DataType::Int32 | DataType::Int64 => value
+            .parse::<i64>()
+            .map(ScalarValue::Int64)
+            .map_err(|e| ExecutionError::General(e.to_string())),
+        DataType::Float32 | DataType::Float64 => value
+            .parse::<f64>()
+            .map(ScalarValue::Float64)
+            .map_err(|e| ExecutionError::General(e.to_string())),
+        _ => Ok(ScalarValue::Utf8(value.to_string())),
+    }
+}
+
+fn path_to_string(path: &Path) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+/// `parse_partition_value` always produces an `Int64`/`Float64`
+/// `ScalarValue` regardless of the declared partition column type (`Int32`
+/// and `Float32` included, since `ScalarValue` has no narrower integer or
+/// float variant). The partition `Field`s must use the same widened type,
+/// or a scan's synthesized partition column array (always `Int64Array`/
+/// `Float64Array`, see `literal_array`) won't match the schema.
+fn widen_partition_type(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Int32 => DataType::Int64,
+        DataType::Float32 => DataType::Float64,
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int64Array;
+    use arrow::datatypes::Field as ArrowField;
+    use arrow::record_batch::RecordBatch;
+    use parquet::arrow::ArrowWriter;
+
+    #[test]
+    fn parse_partition_value_parses_int_columns() {
+        assert_eq!(
+            parse_partition_value("year=2020", "year", &DataType::Int32).unwrap(),
+            ScalarValue::Int64(2020)
+        );
+        assert_eq!(
+            parse_partition_value("year=2020", "year", &DataType::Int64).unwrap(),
+            ScalarValue::Int64(2020)
+        );
+    }
+
+    #[test]
+    fn parse_partition_value_parses_float_columns() {
+        assert_eq!(
+            parse_partition_value("ratio=1.5", "ratio", &DataType::Float32).unwrap(),
+            ScalarValue::Float64(1.5)
+        );
+    }
+
+    #[test]
+    fn parse_partition_value_falls_back_to_utf8() {
+        assert_eq!(
+            parse_partition_value("month=01", "month", &DataType::Utf8).unwrap(),
+            ScalarValue::Utf8("01".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_partition_value_rejects_wrong_column_name() {
+        assert!(parse_partition_value("month=01", "year", &DataType::Int32).is_err());
+    }
+
+    #[test]
+    fn parse_partition_value_rejects_missing_equals() {
+        assert!(parse_partition_value("2020", "year", &DataType::Int32).is_err());
+    }
+
+    #[test]
+    fn widen_partition_type_widens_int32_and_float32() {
+        assert_eq!(widen_partition_type(&DataType::Int32), DataType::Int64);
+        assert_eq!(widen_partition_type(&DataType::Float32), DataType::Float64);
+        assert_eq!(widen_partition_type(&DataType::Utf8), DataType::Utf8);
+    }
+
+    fn scratch_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "datafusion_partitioned_parquet_test_{}_{}",
+            std::process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn discover_files_walks_partition_directories() {
+        let root = scratch_dir("discover");
+        for (year, month) in [("2020", "01"), ("2020", "02")] {
+            let dir = root.join(format!("year={}", year)).join(format!("month={}", month));
+            fs::create_dir_all(&dir).unwrap();
+            File::create(dir.join("data.parquet")).unwrap();
+        }
+        File::create(root.join("README.md")).unwrap();
+
+        let partition_columns = vec![
+            ("year".to_string(), DataType::Int32),
+            ("month".to_string(), DataType::Int32),
+        ];
+        let mut files = vec![];
+        discover_files(&root, &partition_columns, &mut vec![], &mut files).unwrap();
+
+        assert_eq!(files.len(), 2);
+        for file in &files {
+            assert!(file.path.ends_with("data.parquet"));
+            assert!(matches!(file.partition_values[0], ScalarValue::Int64(2020)));
+        }
+        let months: Vec<i64> = files
+            .iter()
+            .map(|f| match f.partition_values[1] {
+                ScalarValue::Int64(v) => v,
+                _ => panic!("expected an Int64 month"),
+            })
+            .collect();
+        assert!(months.contains(&1) && months.contains(&2));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    /// Writes a single-column (`id: Int64`) Parquet file under
+    /// `year=2020/data.parquet`, so `try_new`/`scan` can be exercised against
+    /// a real file without needing a test fixture on disk.
+    fn write_partitioned_fixture(root: &Path) {
+        let dir = root.join("year=2020");
+        fs::create_dir_all(&dir).unwrap();
+        let schema = Arc::new(Schema::new(vec![ArrowField::new("id", DataType::Int64, false)]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(Int64Array::from(vec![1, 2, 3]))]).unwrap();
+        let file = File::create(dir.join("data.parquet")).unwrap();
+        let mut writer = ArrowWriter::try_new(file, schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    #[test]
+    fn try_new_widens_int32_partition_column_to_int64() {
+        let root = scratch_dir("try_new");
+        write_partitioned_fixture(&root);
+
+        let table = PartitionedParquetTable::try_new(
+            root.to_str().unwrap(),
+            vec![("year".to_string(), DataType::Int32)],
+        )
+        .unwrap();
+
+        let year_field = table.schema().field_with_name("year").unwrap().clone();
+        assert_eq!(year_field.data_type(), &DataType::Int64);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn scan_int32_partition_column_produces_matching_batch() {
+        let root = scratch_dir("scan");
+        write_partitioned_fixture(&root);
+
+        let table = PartitionedParquetTable::try_new(
+            root.to_str().unwrap(),
+            vec![("year".to_string(), DataType::Int32)],
+        )
+        .unwrap();
+
+        let plan = table.scan(&None, 1024, &[]).unwrap();
+        let mut partitions = plan.partitions().unwrap();
+        let mut iter = partitions.remove(0).execute().unwrap();
+        let batch = iter.next().unwrap().unwrap();
+
+        let year_index = batch.schema().index_of("year").unwrap();
+        let years = batch
+            .column(year_index)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .expect("year column should be an Int64Array matching the widened schema");
+        assert_eq!((0..years.len()).map(|i| years.value(i)).collect::<Vec<_>>(), vec![2020, 2020, 2020]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,71 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! DataFusion error types
+
+use std::fmt::{Display, Formatter};
+use std::io;
+
+use arrow::error::ArrowError;
+use parquet::errors::ParquetError;
+
+pub type Result<T> = std::result::Result<T, ExecutionError>;
+
+#[derive(Debug)]
+pub enum ExecutionError {
+    IoError(io::Error),
+    ArrowError(ArrowError),
+    ParquetError(ParquetError),
+    NotImplemented(String),
+    General(String),
+}
+
+impl From<io::Error> for ExecutionError {
+    fn from(e: io::Error) -> Self {
+        ExecutionError::IoError(e)
+    }
+}
+
+impl From<ArrowError> for ExecutionError {
+    fn from(e: ArrowError) -> Self {
+        ExecutionError::ArrowError(e)
+    }
+}
+
+impl From<ParquetError> for ExecutionError {
+    fn from(e: ParquetError) -> Self {
+        ExecutionError::ParquetError(e)
+    }
+}
+
+impl From<String> for ExecutionError {
+    fn from(e: String) -> Self {
+        ExecutionError::General(e)
+    }
+}
+
+impl Display for ExecutionError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ExecutionError::IoError(e) => write!(f, "IO error: {}", e),
+            ExecutionError::ArrowError(e) => write!(f, "Arrow error: {}", e),
+            ExecutionError::ParquetError(e) => write!(f, "Parquet error: {}", e),
+            ExecutionError::NotImplemented(s) => write!(f, "Not implemented: {}", s),
+            ExecutionError::General(s) => write!(f, "Execution error: {}", s),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! `ExecutionContext` is the main entry point for interacting with
+//! DataFusion. It tracks registered tables and drives a SQL statement
+//! through the logical plan, optimizer and physical plan stages.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::record_batch::RecordBatch;
+
+use crate::datasource::object_store::{
+    uri_scheme, LocalFileObjectReaderFactory, ObjectReaderFactory,
+};
+use crate::datasource::parquet::ParquetTable;
+use crate::datasource::TableProvider;
+use crate::error::Result;
+use crate::execution::physical_plan::{ExecutionPlan, RecordBatchIterator};
+use crate::logicalplan::LogicalPlan;
+use crate::optimizer::Optimizer;
+use crate::sql::planner::SqlToRel;
+
+pub struct ExecutionContext {
+    datasources: HashMap<String, Arc<dyn TableProvider>>,
+    object_stores: HashMap<String, Arc<dyn ObjectReaderFactory>>,
+}
+
+impl ExecutionContext {
+    pub fn new() -> Self {
+        ExecutionContext {
+            datasources: HashMap::new(),
+            object_stores: HashMap::new(),
+        }
+    }
+
+    /// Register a reader factory for URIs whose scheme (the part before
+    /// `://`) is `scheme`, e.g. `"s3"` for `s3://bucket/key.parquet`.
+    pub fn register_object_store(&mut self, scheme: &str, factory: Arc<dyn ObjectReaderFactory>) {
+        self.object_stores.insert(scheme.to_string(), factory);
+    }
+
+    /// Register a single Parquet file under `name` so that it can be
+    /// referenced from SQL (a partitioned directory is registered with
+    /// `CREATE EXTERNAL TABLE` instead). `uri` may be a plain
+    /// filesystem path, or a URI whose scheme has a factory registered via
+    /// `register_object_store`.
+    pub fn register_parquet(&mut self, name: &str, uri: &str) -> Result<()> {
+        let reader_factory = self.reader_factory_for(uri);
+        let table = ParquetTable::try_new(uri, reader_factory)?;
+        self.register_table(name, Arc::new(table));
+        Ok(())
+    }
+
+    fn reader_factory_for(&self, uri: &str) -> Arc<dyn ObjectReaderFactory> {
+        match uri_scheme(uri).and_then(|scheme| self.object_stores.get(scheme)) {
+            Some(factory) => factory.clone(),
+            None => Arc::new(LocalFileObjectReaderFactory),
+        }
+    }
+
+    pub fn register_table(&mut self, name: &str, provider: Arc<dyn TableProvider>) {
+        self.datasources.insert(name.to_string(), provider);
+    }
+
+    pub fn table(&self, name: &str) -> Option<&Arc<dyn TableProvider>> {
+        self.datasources.get(name)
+    }
+
+    /// Names of every registered table, sorted for stable display (e.g. by
+    /// a `\tables` meta-command in a REPL).
+    pub fn table_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.datasources.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    pub fn create_logical_plan(&self, sql: &str) -> Result<LogicalPlan> {
+        let planner = SqlToRel::new(&self.datasources);
+        planner.statement_to_plan(sql)
+    }
+
+    /// Runs `sql` end to end: DDL (`CREATE EXTERNAL TABLE`) registers a
+    /// table and returns no rows; everything else is planned, optimized and
+    /// collected as usual.
+    pub fn sql(&mut self, sql: &str) -> Result<Vec<RecordBatch>> {
+        let plan = self.create_logical_plan(sql)?;
+        if let LogicalPlan::CreateExternalTable {
+            table_name,
+            table_provider,
+            ..
+        } = &plan
+        {
+            self.register_table(table_name, table_provider.clone());
+            return Ok(vec![]);
+        }
+
+        let plan = self.optimize(&plan)?;
+        let physical_plan = self.create_physical_plan(&plan, 1024 * 1024)?;
+        self.collect(physical_plan.as_ref())
+    }
+
+    pub fn optimize(&self, plan: &LogicalPlan) -> Result<LogicalPlan> {
+        Optimizer::new().optimize(plan)
+    }
+
+    pub fn create_physical_plan(
+        &self,
+        plan: &LogicalPlan,
+        batch_size: usize,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        crate::execution::planner::create_physical_plan(plan, batch_size)
+    }
+
+    /// Execute `plan` and collect every resulting `RecordBatch` into memory.
+    ///
+    /// For large inputs prefer `execute_stream`, which avoids buffering the
+    /// whole result set at once.
+    pub fn collect(&self, plan: &dyn ExecutionPlan) -> Result<Vec<RecordBatch>> {
+        let mut batches = vec![];
+        for partition in plan.partitions()? {
+            let mut iter = partition.execute()?;
+            while let Some(batch) = iter.next()? {
+                batches.push(batch);
+            }
+        }
+        Ok(batches)
+    }
+
+    /// Execute `plan` and return an iterator of `RecordBatch`es.
+    ///
+    /// Unlike `collect`, batches are produced lazily as the caller pulls
+    /// them: each `ExecutionPlan` partition (e.g. one Parquet file) is only
+    /// executed once the previous one is exhausted, and within it batches
+    /// are decoded on demand (a Parquet scan decodes one row group at a
+    /// time), so peak memory is bounded by a single row group rather than
+    /// the whole result set.
+    pub fn execute_stream(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+    ) -> Result<RecordBatchStream> {
+        let partitions = plan.partitions()?.into_iter();
+        Ok(RecordBatchStream {
+            partitions,
+            current: None,
+        })
+    }
+}
+
+impl Default for ExecutionContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator over the `RecordBatch`es produced by an `ExecutionPlan`,
+/// pulling one partition at a time and, within a partition, one batch at a
+/// time. See `ExecutionContext::execute_stream`.
+pub struct RecordBatchStream {
+    partitions: std::vec::IntoIter<Arc<dyn crate::execution::physical_plan::Partition>>,
+    current: Option<Box<dyn RecordBatchIterator>>,
+}
+
+impl Iterator for RecordBatchStream {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(iter) = self.current.as_mut() {
+                match iter.next() {
+                    Ok(Some(batch)) => return Some(Ok(batch)),
+                    Ok(None) => self.current = None,
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+
+            let partition = self.partitions.next()?;
+            match partition.execute() {
+                Ok(iter) => self.current = Some(iter),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
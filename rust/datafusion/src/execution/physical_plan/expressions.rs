@@ -0,0 +1,197 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Evaluates a logical `Expr` against a `RecordBatch`, producing the
+//! resulting column. Shared by `FilterExec` (which expects a boolean
+//! result) and `ProjectionExec` (one column per select-list expression).
+
+use std::sync::Arc;
+
+use arrow::array::{ArrayRef, BooleanArray, Float64Array, Int64Array, StringArray};
+use arrow::compute;
+use arrow::datatypes::DataType;
+use arrow::record_batch::RecordBatch;
+
+use crate::error::{ExecutionError, Result};
+use crate::logicalplan::{Expr, Operator, ScalarValue};
+
+pub fn evaluate(expr: &Expr, batch: &RecordBatch) -> Result<ArrayRef> {
+    match expr {
+        Expr::Column(name) => {
+            let index = batch.schema().index_of(name)?;
+            Ok(batch.column(index).clone())
+        }
+        Expr::Literal(value) => Ok(literal_array(value, batch.num_rows())),
+        Expr::Cast { expr, data_type } => {
+            let array = evaluate(expr, batch)?;
+            Ok(compute::cast(&array, data_type)?)
+        }
+        Expr::Between { expr, low, high } => {
+            let value = evaluate(expr, batch)?;
+            let low = evaluate(low, batch)?;
+            let high = evaluate(high, batch)?;
+            let ge_low = compare_numeric(&value, &low, Operator::GtEq)?;
+            let le_high = compare_numeric(&value, &high, Operator::LtEq)?;
+            Ok(Arc::new(compute::and(&ge_low, &le_high)?))
+        }
+        Expr::BinaryExpr { left, op, right } => evaluate_binary(left, *op, right, batch),
+    }
+}
+
+fn evaluate_binary(left: &Expr, op: Operator, right: &Expr, batch: &RecordBatch) -> Result<ArrayRef> {
+    match op {
+        Operator::And => {
+            let l = evaluate(left, batch)?;
+            let r = evaluate(right, batch)?;
+            Ok(Arc::new(compute::and(as_boolean(&l)?, as_boolean(&r)?)?))
+        }
+        Operator::Or => {
+            let l = evaluate(left, batch)?;
+            let r = evaluate(right, batch)?;
+            Ok(Arc::new(compute::or(as_boolean(&l)?, as_boolean(&r)?)?))
+        }
+        _ => {
+            let l = evaluate(left, batch)?;
+            let r = evaluate(right, batch)?;
+            Ok(Arc::new(compare_numeric(&l, &r, op)?))
+        }
+    }
+}
+
+/// Compares two arrays numerically, casting both to `Float64` first. This
+/// mirrors the min/max comparisons `pruning.rs` does against Parquet
+/// statistics, and covers every comparison the SQL front end can currently
+/// produce (`parse_comparison`/`parse_predicate` only build numeric and
+/// column/literal comparisons).
+fn compare_numeric(left: &ArrayRef, right: &ArrayRef, op: Operator) -> Result<BooleanArray> {
+    let left = compute::cast(left, &DataType::Float64)?;
+    let right = compute::cast(right, &DataType::Float64)?;
+    let left = left.as_any().downcast_ref::<Float64Array>().unwrap();
+    let right = right.as_any().downcast_ref::<Float64Array>().unwrap();
+    Ok(match op {
+        Operator::Eq => compute::eq(left, right)?,
+        Operator::NotEq => compute::neq(left, right)?,
+        Operator::Lt => compute::lt(left, right)?,
+        Operator::LtEq => compute::lt_eq(left, right)?,
+        Operator::Gt => compute::gt(left, right)?,
+        Operator::GtEq => compute::gt_eq(left, right)?,
+        other => {
+            return Err(ExecutionError::NotImplemented(format!(
+                "operator {:?} is not a comparison",
+                other
+            )))
+        }
+    })
+}
+
+fn as_boolean(array: &ArrayRef) -> Result<&BooleanArray> {
+    array
+        .as_any()
+        .downcast_ref::<BooleanArray>()
+        .ok_or_else(|| ExecutionError::General("expected a boolean expression".to_string()))
+}
+
+/// Builds a column of `num_rows` copies of `value`, so a literal can be
+/// compared or combined with a column array element-wise.
+pub(crate) fn literal_array(value: &ScalarValue, num_rows: usize) -> ArrayRef {
+    match value {
+        ScalarValue::Int64(v) => Arc::new(Int64Array::from(vec![*v; num_rows])),
+        ScalarValue::Float64(v) => Arc::new(Float64Array::from(vec![*v; num_rows])),
+        ScalarValue::Boolean(v) => Arc::new(BooleanArray::from(vec![*v; num_rows])),
+        ScalarValue::Utf8(v) => Arc::new(StringArray::from(vec![v.as_str(); num_rows])),
+        ScalarValue::Null => Arc::new(BooleanArray::from(vec![None; num_rows])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int32Array;
+    use arrow::datatypes::{Field, Schema};
+    use std::sync::Arc as StdArc;
+
+    fn batch() -> RecordBatch {
+        let schema = StdArc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("value", DataType::Float64, false),
+        ]));
+        RecordBatch::try_new(
+            schema,
+            vec![
+                StdArc::new(Int32Array::from(vec![1, 2, 3, 4])),
+                StdArc::new(Float64Array::from(vec![10.0, 20.0, 30.0, 40.0])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn evaluates_gt_against_literal() {
+        let expr = Expr::BinaryExpr {
+            left: Box::new(Expr::Column("id".to_string())),
+            op: Operator::Gt,
+            right: Box::new(Expr::Literal(ScalarValue::Int64(2))),
+        };
+        let result = evaluate(&expr, &batch()).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!((0..result.len()).map(|i| result.value(i)).collect::<Vec<bool>>(), vec![false, false, true, true]);
+    }
+
+    #[test]
+    fn evaluates_not_eq() {
+        let expr = Expr::BinaryExpr {
+            left: Box::new(Expr::Column("id".to_string())),
+            op: Operator::NotEq,
+            right: Box::new(Expr::Literal(ScalarValue::Int64(2))),
+        };
+        let result = evaluate(&expr, &batch()).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!((0..result.len()).map(|i| result.value(i)).collect::<Vec<bool>>(), vec![true, false, true, true]);
+    }
+
+    #[test]
+    fn evaluates_between() {
+        let expr = Expr::Between {
+            expr: Box::new(Expr::Column("id".to_string())),
+            low: Box::new(Expr::Literal(ScalarValue::Int64(2))),
+            high: Box::new(Expr::Literal(ScalarValue::Int64(3))),
+        };
+        let result = evaluate(&expr, &batch()).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!((0..result.len()).map(|i| result.value(i)).collect::<Vec<bool>>(), vec![false, true, true, false]);
+    }
+
+    #[test]
+    fn evaluates_and() {
+        let expr = Expr::BinaryExpr {
+            left: Box::new(Expr::BinaryExpr {
+                left: Box::new(Expr::Column("id".to_string())),
+                op: Operator::Gt,
+                right: Box::new(Expr::Literal(ScalarValue::Int64(1))),
+            }),
+            op: Operator::And,
+            right: Box::new(Expr::BinaryExpr {
+                left: Box::new(Expr::Column("value".to_string())),
+                op: Operator::Lt,
+                right: Box::new(Expr::Literal(ScalarValue::Float64(35.0))),
+            }),
+        };
+        let result = evaluate(&expr, &batch()).unwrap();
+        let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!((0..result.len()).map(|i| result.value(i)).collect::<Vec<bool>>(), vec![false, true, true, false]);
+    }
+}
@@ -0,0 +1,165 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Executes a `Selection`: pulls batches from its input and discards rows
+//! that don't satisfy the predicate.
+//!
+//! Row-group/partition pruning (see `pruning.rs`) only ever proves a whole
+//! row group or file can't match anything and skips reading it; it never
+//! discards individual non-matching rows within a row group that is kept.
+//! This operator is what actually enforces the predicate row by row.
+
+use std::sync::Arc;
+
+use arrow::array::BooleanArray;
+use arrow::compute;
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+
+use crate::error::{ExecutionError, Result};
+use crate::execution::physical_plan::expressions::evaluate;
+use crate::execution::physical_plan::{ExecutionPlan, Partition, RecordBatchIterator};
+use crate::logicalplan::Expr;
+
+/// Physical counterpart of `LogicalPlan::Selection`.
+pub struct FilterExec {
+    input: Arc<dyn ExecutionPlan>,
+    predicate: Expr,
+}
+
+impl FilterExec {
+    pub fn new(input: Arc<dyn ExecutionPlan>, predicate: Expr) -> Self {
+        FilterExec { input, predicate }
+    }
+
+    /// The plan this filter reads from, e.g. so a caller can look past this
+    /// operator to inspect a wrapped scan's metrics.
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+}
+
+impl ExecutionPlan for FilterExec {
+    fn schema(&self) -> SchemaRef {
+        self.input.schema()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn partitions(&self) -> Result<Vec<Arc<dyn Partition>>> {
+        Ok(self
+            .input
+            .partitions()?
+            .into_iter()
+            .map(|input| {
+                Arc::new(FilterPartition {
+                    input,
+                    predicate: self.predicate.clone(),
+                }) as Arc<dyn Partition>
+            })
+            .collect())
+    }
+}
+
+struct FilterPartition {
+    input: Arc<dyn Partition>,
+    predicate: Expr,
+}
+
+impl Partition for FilterPartition {
+    fn execute(&self) -> Result<Box<dyn RecordBatchIterator>> {
+        Ok(Box::new(FilterIterator {
+            input: self.input.execute()?,
+            predicate: self.predicate.clone(),
+        }))
+    }
+}
+
+struct FilterIterator {
+    input: Box<dyn RecordBatchIterator>,
+    predicate: Expr,
+}
+
+impl RecordBatchIterator for FilterIterator {
+    fn schema(&self) -> SchemaRef {
+        self.input.schema()
+    }
+
+    fn next(&mut self) -> Result<Option<RecordBatch>> {
+        loop {
+            let batch = match self.input.next()? {
+                Some(batch) => batch,
+                None => return Ok(None),
+            };
+
+            let mask = evaluate(&self.predicate, &batch)?;
+            let mask = mask.as_any().downcast_ref::<BooleanArray>().ok_or_else(|| {
+                ExecutionError::General("WHERE predicate did not evaluate to a boolean".to_string())
+            })?;
+
+            let columns = batch
+                .columns()
+                .iter()
+                .map(|column| compute::filter(column.as_ref(), mask))
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            let filtered = RecordBatch::try_new(batch.schema(), columns)?;
+
+            if filtered.num_rows() > 0 {
+                return Ok(Some(filtered));
+            }
+            // Every row in this batch was filtered out; pull the next one
+            // rather than returning an empty batch.
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::physical_plan::tests::single_batch_plan;
+    use crate::logicalplan::ScalarValue;
+    use arrow::array::Int64Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    #[test]
+    fn filters_out_non_matching_rows() {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Int64Array::from(vec![1, 2, 3, 4]))],
+        )
+        .unwrap();
+        let input = single_batch_plan(batch);
+
+        let predicate = Expr::BinaryExpr {
+            left: Box::new(Expr::Column("id".to_string())),
+            op: crate::logicalplan::Operator::Gt,
+            right: Box::new(Expr::Literal(ScalarValue::Int64(2))),
+        };
+        let plan = FilterExec::new(input, predicate);
+
+        let mut partitions = plan.partitions().unwrap();
+        let mut iter = partitions.remove(0).execute().unwrap();
+        let result = iter.next().unwrap().unwrap();
+
+        let ids = result.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!((0..ids.len()).map(|i| ids.value(i)).collect::<Vec<_>>(), vec![3, 4]);
+        assert!(iter.next().unwrap().is_none());
+    }
+}
@@ -0,0 +1,183 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Physical query plan: the executable counterpart of a `LogicalPlan`.
+
+pub mod expressions;
+pub mod filter;
+pub mod parquet;
+pub mod projection;
+pub mod pruning;
+
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+
+use crate::error::Result;
+
+/// A physical operator that can be executed, producing zero or more
+/// partitions of `RecordBatch`es.
+pub trait ExecutionPlan: Send + Sync {
+    /// The schema of the batches produced by this plan.
+    fn schema(&self) -> SchemaRef;
+
+    /// The partitions that make up this plan. Each partition can be iterated
+    /// independently, which is what callers use to parallelize execution.
+    fn partitions(&self) -> Result<Vec<Arc<dyn Partition>>>;
+
+    /// Enables downcasting to a concrete plan type, e.g. to inspect
+    /// scan-specific metrics such as `ParquetExec`'s row-group pruning
+    /// counters.
+    fn as_any(&self) -> &dyn std::any::Any;
+}
+
+/// Walks down through any `ProjectionExec`/`FilterExec` wrapper nodes to
+/// find the `ParquetExec` scan underneath, e.g. so a caller can report its
+/// row-group pruning metrics even though the top-level plan for a SQL query
+/// is always wrapped (`ProjectionExec(ParquetExec)`, or
+/// `ProjectionExec(FilterExec(ParquetExec))` with a `WHERE` clause).
+/// Returns `None` if no `ParquetExec` is found.
+pub fn find_parquet_exec(plan: &Arc<dyn ExecutionPlan>) -> Option<&parquet::ParquetExec> {
+    if let Some(parquet_exec) = plan.as_any().downcast_ref::<parquet::ParquetExec>() {
+        return Some(parquet_exec);
+    }
+    if let Some(projection) = plan.as_any().downcast_ref::<projection::ProjectionExec>() {
+        return find_parquet_exec(projection.input());
+    }
+    if let Some(filter) = plan.as_any().downcast_ref::<filter::FilterExec>() {
+        return find_parquet_exec(filter.input());
+    }
+    None
+}
+
+/// A single partition of an `ExecutionPlan`, capable of producing a
+/// `RecordBatchIterator` that yields its batches lazily.
+pub trait Partition: Send + Sync {
+    fn execute(&self) -> Result<Box<dyn RecordBatchIterator>>;
+}
+
+/// An iterator over `RecordBatch`es produced by executing a partition.
+///
+/// Implementations are expected to decode and hold only as much data as is
+/// needed to produce the next batch, so that a caller pulling batches one at
+/// a time (rather than collecting them all up front) sees bounded memory
+/// use.
+pub trait RecordBatchIterator {
+    fn schema(&self) -> SchemaRef;
+
+    /// Returns the next batch, or `Ok(None)` when the partition is exhausted.
+    fn next(&mut self) -> Result<Option<RecordBatch>>;
+}
+
+/// Test-only helpers shared by the operator test modules (`filter`,
+/// `projection`) for exercising an `ExecutionPlan` without a real data
+/// source.
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    pub(crate) fn single_batch_plan(batch: RecordBatch) -> Arc<dyn ExecutionPlan> {
+        Arc::new(SingleBatchExec(batch))
+    }
+
+    struct SingleBatchExec(RecordBatch);
+
+    impl ExecutionPlan for SingleBatchExec {
+        fn schema(&self) -> SchemaRef {
+            self.0.schema()
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn partitions(&self) -> Result<Vec<Arc<dyn Partition>>> {
+            Ok(vec![Arc::new(SingleBatchPartition(self.0.clone()))])
+        }
+    }
+
+    struct SingleBatchPartition(RecordBatch);
+
+    impl Partition for SingleBatchPartition {
+        fn execute(&self) -> Result<Box<dyn RecordBatchIterator>> {
+            Ok(Box::new(SingleBatchIterator {
+                schema: self.0.schema(),
+                batch: Mutex::new(Some(self.0.clone())),
+            }))
+        }
+    }
+
+    struct SingleBatchIterator {
+        schema: SchemaRef,
+        batch: Mutex<Option<RecordBatch>>,
+    }
+
+    impl RecordBatchIterator for SingleBatchIterator {
+        fn schema(&self) -> SchemaRef {
+            self.schema.clone()
+        }
+
+        fn next(&mut self) -> Result<Option<RecordBatch>> {
+            Ok(self.batch.lock().unwrap().take())
+        }
+    }
+
+    #[test]
+    fn find_parquet_exec_looks_past_projection_and_filter_wrappers() {
+        use crate::datasource::object_store::LocalFileObjectReaderFactory;
+        use crate::execution::physical_plan::filter::FilterExec;
+        use crate::execution::physical_plan::parquet::ParquetExec;
+        use crate::execution::physical_plan::projection::ProjectionExec;
+        use crate::logicalplan::{Expr, Operator, ScalarValue};
+        use arrow::datatypes::{DataType, Field, Schema};
+
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        let parquet_exec: Arc<dyn ExecutionPlan> = Arc::new(ParquetExec::new(
+            vec![],
+            schema.clone(),
+            vec![0],
+            1024,
+            vec![],
+            Arc::new(LocalFileObjectReaderFactory),
+        ));
+
+        let predicate = Expr::BinaryExpr {
+            left: Box::new(Expr::Column("id".to_string())),
+            op: Operator::Gt,
+            right: Box::new(Expr::Literal(ScalarValue::Int64(0))),
+        };
+        let filter_exec: Arc<dyn ExecutionPlan> =
+            Arc::new(FilterExec::new(parquet_exec, predicate));
+
+        let projection_exec: Arc<dyn ExecutionPlan> = Arc::new(ProjectionExec::new(
+            filter_exec,
+            vec![Expr::Column("id".to_string())],
+            schema,
+        ));
+
+        assert!(find_parquet_exec(&projection_exec).is_some());
+    }
+
+    #[test]
+    fn find_parquet_exec_returns_none_when_absent() {
+        let batch = RecordBatch::new_empty(Arc::new(arrow::datatypes::Schema::empty()));
+        let plan = single_batch_plan(batch);
+        assert!(find_parquet_exec(&plan).is_none());
+    }
+}
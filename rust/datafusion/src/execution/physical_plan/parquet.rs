@@ -0,0 +1,361 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Physical plan for scanning Parquet files.
+//!
+//! Each partition corresponds to one file, and within a file, row groups are
+//! read and decoded one at a time so that peak memory is bounded by a single
+//! row group rather than the whole file. Row groups that the filter
+//! predicates provably can't match, per the footer's min/max statistics, are
+//! skipped without reading their column chunks at all.
+//!
+//! A file may also carry constant partition column values (see
+//! `PartitionedFile`), derived from its location in a Hive-style directory
+//! tree; these are appended to every batch read from that file rather than
+//! being read from the Parquet columns themselves.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::{Field, SchemaRef};
+use arrow::record_batch::RecordBatch;
+use parquet::arrow::{ArrowReader, ParquetFileArrowReader};
+use parquet::errors::Result as ParquetResult;
+use parquet::file::metadata::ParquetMetaData;
+use parquet::file::reader::{FileReader, RowGroupReader, SerializedFileReader};
+use parquet::record::reader::RowIter;
+use parquet::schema::types::Type as SchemaType;
+
+use crate::datasource::object_store::{ObjectReaderAdapter, ObjectReaderFactory};
+use crate::datasource::PartitionedFile;
+use crate::error::{ExecutionError, Result};
+use crate::execution::physical_plan::expressions::literal_array;
+use crate::execution::physical_plan::pruning;
+use crate::execution::physical_plan::{ExecutionPlan, Partition, RecordBatchIterator};
+use crate::logicalplan::{Expr, ScalarValue};
+
+/// Row-group-pruning counters for a `ParquetExec`, shared across its
+/// partitions so callers can see how effective pruning was for the whole
+/// scan.
+#[derive(Default)]
+pub struct ParquetExecMetrics {
+    pub row_groups_total: AtomicUsize,
+    pub row_groups_pruned: AtomicUsize,
+}
+
+/// Execution plan for a Parquet scan over one or more files.
+pub struct ParquetExec {
+    files: Vec<PartitionedFile>,
+    file_schema: SchemaRef,
+    partition_columns: Vec<Field>,
+    /// Combined output schema: `file_schema`'s fields followed by
+    /// `partition_columns`.
+    schema: SchemaRef,
+    projection: Vec<usize>,
+    batch_size: usize,
+    filters: Vec<Expr>,
+    reader_factory: Arc<dyn ObjectReaderFactory>,
+    metrics: Arc<ParquetExecMetrics>,
+}
+
+impl ParquetExec {
+    /// A scan over plain (non-partitioned) files sharing `schema`, read
+    /// through `reader_factory`.
+    pub fn new(
+        uris: Vec<String>,
+        schema: SchemaRef,
+        projection: Vec<usize>,
+        batch_size: usize,
+        filters: Vec<Expr>,
+        reader_factory: Arc<dyn ObjectReaderFactory>,
+    ) -> Self {
+        let files = uris
+            .into_iter()
+            .map(|path| PartitionedFile {
+                path,
+                partition_values: vec![],
+            })
+            .collect();
+        ParquetExec::with_partitioned_files(
+            files,
+            schema,
+            vec![],
+            projection,
+            batch_size,
+            filters,
+            reader_factory,
+        )
+    }
+
+    /// A scan over files that also carry constant partition column values,
+    /// e.g. discovered under a `PARTITIONED BY` directory tree, read through
+    /// `reader_factory` (`PartitionedParquetTable` passes the local
+    /// filesystem factory, since its discovery walks a local directory tree).
+    pub fn with_partitioned_files(
+        files: Vec<PartitionedFile>,
+        file_schema: SchemaRef,
+        partition_columns: Vec<Field>,
+        projection: Vec<usize>,
+        batch_size: usize,
+        filters: Vec<Expr>,
+        reader_factory: Arc<dyn ObjectReaderFactory>,
+    ) -> Self {
+        let mut all_fields = file_schema.fields().clone();
+        all_fields.extend(partition_columns.iter().cloned());
+        let schema = Arc::new(arrow::datatypes::Schema::new(all_fields));
+        ParquetExec {
+            files,
+            file_schema,
+            partition_columns,
+            schema,
+            projection,
+            batch_size,
+            filters,
+            reader_factory,
+            metrics: Arc::new(ParquetExecMetrics::default()),
+        }
+    }
+
+    /// Row-group pruning counters accumulated as partitions of this scan are
+    /// executed; only meaningful once execution has started.
+    pub fn metrics(&self) -> Arc<ParquetExecMetrics> {
+        self.metrics.clone()
+    }
+}
+
+impl ExecutionPlan for ParquetExec {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn partitions(&self) -> Result<Vec<Arc<dyn Partition>>> {
+        let file_ncols = self.file_schema.fields().len();
+        Ok(self
+            .files
+            .iter()
+            .map(|file| {
+                Arc::new(ParquetPartition {
+                    file: file.clone(),
+                    file_schema: self.file_schema.clone(),
+                    partition_columns: self.partition_columns.clone(),
+                    schema: self.schema.clone(),
+                    file_ncols,
+                    projection: self.projection.clone(),
+                    batch_size: self.batch_size,
+                    filters: self.filters.clone(),
+                    reader_factory: self.reader_factory.clone(),
+                    metrics: self.metrics.clone(),
+                }) as Arc<dyn Partition>
+            })
+            .collect())
+    }
+}
+
+struct ParquetPartition {
+    file: PartitionedFile,
+    file_schema: SchemaRef,
+    partition_columns: Vec<Field>,
+    schema: SchemaRef,
+    file_ncols: usize,
+    projection: Vec<usize>,
+    batch_size: usize,
+    filters: Vec<Expr>,
+    reader_factory: Arc<dyn ObjectReaderFactory>,
+    metrics: Arc<ParquetExecMetrics>,
+}
+
+impl Partition for ParquetPartition {
+    fn execute(&self) -> Result<Box<dyn RecordBatchIterator>> {
+        let uri = &self.file.path;
+        let object_reader = self.reader_factory.create_reader(uri)?;
+        let chunk_reader = ObjectReaderAdapter(object_reader);
+        let file_reader = Arc::new(SerializedFileReader::new(chunk_reader).map_err(|e| {
+            ExecutionError::General(format!("failed to open {}: {}", uri, e))
+        })?);
+        let num_row_groups = file_reader.num_row_groups();
+
+        // Indices in `projection` that land in the Parquet file itself vs.
+        // the synthesized partition columns appended after it.
+        let file_projection: Vec<usize> = self
+            .projection
+            .iter()
+            .filter(|&&i| i < self.file_ncols)
+            .cloned()
+            .collect();
+
+        Ok(Box::new(ParquetRowGroupIterator {
+            file_reader,
+            schema: projected_schema(&self.schema, &self.projection),
+            projection: self.projection.clone(),
+            file_projection,
+            file_ncols: self.file_ncols,
+            partition_columns: self.partition_columns.clone(),
+            partition_values: self.file.partition_values.clone(),
+            batch_size: self.batch_size,
+            filters: self.filters.clone(),
+            table_schema: self.file_schema.clone(),
+            num_row_groups,
+            current_row_group: 0,
+            current_reader: None,
+            metrics: self.metrics.clone(),
+        }))
+    }
+}
+
+fn projected_schema(schema: &SchemaRef, projection: &[usize]) -> SchemaRef {
+    let fields = projection
+        .iter()
+        .map(|&i| schema.field(i).clone())
+        .collect();
+    Arc::new(arrow::datatypes::Schema::new(fields))
+}
+
+/// Yields one `RecordBatch` at a time, decoding a single row group on
+/// demand and dropping it once its batches have been produced.
+struct ParquetRowGroupIterator {
+    file_reader: Arc<SerializedFileReader<ObjectReaderAdapter>>,
+    schema: SchemaRef,
+    projection: Vec<usize>,
+    file_projection: Vec<usize>,
+    file_ncols: usize,
+    partition_columns: Vec<Field>,
+    partition_values: Vec<ScalarValue>,
+    batch_size: usize,
+    filters: Vec<Expr>,
+    table_schema: SchemaRef,
+    num_row_groups: usize,
+    current_row_group: usize,
+    current_reader: Option<Box<dyn Iterator<Item = arrow::error::Result<RecordBatch>>>>,
+    metrics: Arc<ParquetExecMetrics>,
+}
+
+impl ParquetRowGroupIterator {
+    /// Builds the final projected batch, interleaving columns read from the
+    /// file with constant arrays for any requested partition columns.
+    fn with_partition_columns(&self, file_batch: RecordBatch) -> Result<RecordBatch> {
+        if self.partition_columns.is_empty() {
+            return Ok(file_batch);
+        }
+        let num_rows = file_batch.num_rows();
+        let mut file_col = 0;
+        let columns: Vec<ArrayRef> = self
+            .projection
+            .iter()
+            .map(|&i| {
+                if i < self.file_ncols {
+                    let col = file_batch.column(file_col).clone();
+                    file_col += 1;
+                    col
+                } else {
+                    let partition_index = i - self.file_ncols;
+                    literal_array(&self.partition_values[partition_index], num_rows)
+                }
+            })
+            .collect();
+        Ok(RecordBatch::try_new(self.schema.clone(), columns)?)
+    }
+}
+
+impl RecordBatchIterator for ParquetRowGroupIterator {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn next(&mut self) -> Result<Option<RecordBatch>> {
+        loop {
+            if let Some(reader) = self.current_reader.as_mut() {
+                if let Some(batch) = reader.next() {
+                    return Ok(Some(self.with_partition_columns(batch?)?));
+                }
+                // This row group is exhausted; drop its reader (and the
+                // column chunks it has decoded) before moving on.
+                self.current_reader = None;
+            }
+
+            if self.current_row_group >= self.num_row_groups {
+                return Ok(None);
+            }
+
+            let row_group_index = self.current_row_group;
+            self.current_row_group += 1;
+            self.metrics.row_groups_total.fetch_add(1, Ordering::Relaxed);
+
+            if !self.filters.is_empty() {
+                let row_group_meta = self.file_reader.metadata().row_group(row_group_index);
+                if pruning::prunes_row_group(&self.filters, &self.table_schema, row_group_meta) {
+                    self.metrics.row_groups_pruned.fetch_add(1, Ordering::Relaxed);
+                    continue;
+                }
+            }
+
+            let single_row_group: Arc<dyn FileReader> = Arc::new(SingleRowGroupReader::new(
+                self.file_reader.clone(),
+                row_group_index,
+            ));
+            let mut arrow_reader = ParquetFileArrowReader::new(single_row_group);
+            let reader = arrow_reader
+                .get_record_reader_by_columns(self.file_projection.clone(), self.batch_size)?;
+            self.current_reader = Some(Box::new(reader));
+        }
+    }
+}
+
+/// Presents a single row group of an underlying `FileReader` as a
+/// stand-alone one-row-group file, so `ParquetFileArrowReader` (which always
+/// reads across every row group its `FileReader` reports) can be pointed at
+/// exactly the row group being decoded.
+struct SingleRowGroupReader {
+    inner: Arc<SerializedFileReader<ObjectReaderAdapter>>,
+    metadata: ParquetMetaData,
+    row_group_index: usize,
+}
+
+impl SingleRowGroupReader {
+    fn new(inner: Arc<SerializedFileReader<ObjectReaderAdapter>>, row_group_index: usize) -> Self {
+        let row_group = inner.metadata().row_group(row_group_index).clone();
+        let file_metadata = inner.metadata().file_metadata().clone();
+        let metadata = ParquetMetaData::new(file_metadata, vec![row_group]);
+        SingleRowGroupReader {
+            inner,
+            metadata,
+            row_group_index,
+        }
+    }
+}
+
+impl FileReader for SingleRowGroupReader {
+    fn metadata(&self) -> &ParquetMetaData {
+        &self.metadata
+    }
+
+    fn num_row_groups(&self) -> usize {
+        1
+    }
+
+    fn get_row_group(&self, _i: usize) -> ParquetResult<Box<dyn RowGroupReader + '_>> {
+        self.inner.get_row_group(self.row_group_index)
+    }
+
+    fn get_row_iter(&self, projection: Option<SchemaType>) -> ParquetResult<RowIter<'_>> {
+        self.inner.get_row_iter(projection)
+    }
+}
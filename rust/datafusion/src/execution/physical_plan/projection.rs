@@ -0,0 +1,150 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Executes a `Projection`: evaluates each select-list expression (a
+//! column reference or a `CAST`) against every input batch, producing the
+//! projected/cast output rather than passing the input's columns through
+//! untransformed.
+
+use std::sync::Arc;
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+
+use crate::error::Result;
+use crate::execution::physical_plan::expressions::evaluate;
+use crate::execution::physical_plan::{ExecutionPlan, Partition, RecordBatchIterator};
+use crate::logicalplan::Expr;
+
+/// Physical counterpart of `LogicalPlan::Projection`.
+pub struct ProjectionExec {
+    input: Arc<dyn ExecutionPlan>,
+    expr: Vec<Expr>,
+    schema: SchemaRef,
+}
+
+impl ProjectionExec {
+    pub fn new(input: Arc<dyn ExecutionPlan>, expr: Vec<Expr>, schema: SchemaRef) -> Self {
+        ProjectionExec { input, expr, schema }
+    }
+
+    /// The plan this projection reads from, e.g. so a caller can look past
+    /// this operator to inspect a wrapped scan's metrics.
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+}
+
+impl ExecutionPlan for ProjectionExec {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn partitions(&self) -> Result<Vec<Arc<dyn Partition>>> {
+        Ok(self
+            .input
+            .partitions()?
+            .into_iter()
+            .map(|input| {
+                Arc::new(ProjectionPartition {
+                    input,
+                    expr: self.expr.clone(),
+                    schema: self.schema.clone(),
+                }) as Arc<dyn Partition>
+            })
+            .collect())
+    }
+}
+
+struct ProjectionPartition {
+    input: Arc<dyn Partition>,
+    expr: Vec<Expr>,
+    schema: SchemaRef,
+}
+
+impl Partition for ProjectionPartition {
+    fn execute(&self) -> Result<Box<dyn RecordBatchIterator>> {
+        Ok(Box::new(ProjectionIterator {
+            input: self.input.execute()?,
+            expr: self.expr.clone(),
+            schema: self.schema.clone(),
+        }))
+    }
+}
+
+struct ProjectionIterator {
+    input: Box<dyn RecordBatchIterator>,
+    expr: Vec<Expr>,
+    schema: SchemaRef,
+}
+
+impl RecordBatchIterator for ProjectionIterator {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn next(&mut self) -> Result<Option<RecordBatch>> {
+        let batch = match self.input.next()? {
+            Some(batch) => batch,
+            None => return Ok(None),
+        };
+        let columns = self
+            .expr
+            .iter()
+            .map(|e| evaluate(e, &batch))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(RecordBatch::try_new(self.schema.clone(), columns)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::execution::physical_plan::tests::single_batch_plan;
+    use arrow::array::Float64Array;
+    use arrow::datatypes::{DataType, Field, Schema};
+
+    #[test]
+    fn projects_and_casts_select_list() {
+        let schema = Arc::new(Schema::new(vec![Field::new("price", DataType::Float64, false)]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(Float64Array::from(vec![1.5, 2.5]))],
+        )
+        .unwrap();
+        let input = single_batch_plan(batch);
+
+        let output_schema = Arc::new(Schema::new(vec![Field::new("price_int", DataType::Int64, false)]));
+        let expr = vec![Expr::Cast {
+            expr: Box::new(Expr::Column("price".to_string())),
+            data_type: DataType::Int64,
+        }];
+        let plan = ProjectionExec::new(input, expr, output_schema);
+
+        let mut partitions = plan.partitions().unwrap();
+        let mut iter = partitions.remove(0).execute().unwrap();
+        let result = iter.next().unwrap().unwrap();
+
+        assert_eq!(result.schema().field(0).name(), "price_int");
+        let values = result.column(0).as_any().downcast_ref::<arrow::array::Int64Array>().unwrap();
+        assert_eq!((0..values.len()).map(|i| values.value(i)).collect::<Vec<_>>(), vec![1, 2]);
+    }
+}
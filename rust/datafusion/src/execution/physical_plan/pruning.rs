@@ -0,0 +1,291 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Row group pruning using the min/max statistics stored in the Parquet
+//! footer.
+//!
+//! A row group is only skipped when we can *prove* from its statistics that
+//! no row inside it can satisfy a predicate; row groups with missing stats,
+//! or predicates we don't know how to reason about, are conservatively kept.
+
+use arrow::datatypes::{Field, Schema};
+use parquet::file::metadata::RowGroupMetaData;
+use parquet::file::statistics::Statistics;
+
+use crate::logicalplan::{Expr, Operator, ScalarValue};
+
+/// Returns `true` if `row_group` can be proven, from its column
+/// statistics, to contain no row matching every filter in `filters`.
+pub fn prunes_row_group(
+    filters: &[Expr],
+    schema: &Schema,
+    row_group: &RowGroupMetaData,
+) -> bool {
+    filters
+        .iter()
+        .any(|filter| filter_prunes_row_group(filter, schema, row_group))
+}
+
+fn filter_prunes_row_group(filter: &Expr, schema: &Schema, row_group: &RowGroupMetaData) -> bool {
+    if let Expr::Between { expr, low, high } = filter {
+        return between_prunes_row_group(expr, low, high, schema, row_group);
+    }
+
+    let (column, op, literal) = match as_column_comparison(filter) {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    let column_index = match schema.index_of(&column) {
+        Ok(i) => i,
+        Err(_) => return false,
+    };
+
+    let stats = match row_group.column(column_index).statistics() {
+        Some(s) if s.has_min_max_set() => s,
+        _ => return false,
+    };
+
+    let (min, max) = match stats_as_f64(stats) {
+        Some(bounds) => bounds,
+        None => return false,
+    };
+    let value = match literal {
+        ScalarValue::Int64(v) => v as f64,
+        ScalarValue::Float64(v) => v,
+        _ => return false,
+    };
+
+    match op {
+        // col > value: prune when no row can exceed `value`, i.e. max <= value
+        Operator::Gt => max <= value,
+        Operator::GtEq => max < value,
+        // col < value: prune when every row is already >= value, i.e. min >= value
+        Operator::Lt => min >= value,
+        Operator::LtEq => min > value,
+        Operator::Eq => value < min || value > max,
+        // col != value could only be pruned when the row group is a single
+        // constant equal to `value` (min == max == value); that case is not
+        // checked here, so conservatively never prune.
+        Operator::NotEq => false,
+        _ => false,
+    }
+}
+
+/// `expr BETWEEN low AND high` is equivalent to `expr >= low AND expr <=
+/// high`; prune when the row group's range can't overlap `[low, high]`.
+fn between_prunes_row_group(
+    expr: &Expr,
+    low: &Expr,
+    high: &Expr,
+    schema: &Schema,
+    row_group: &RowGroupMetaData,
+) -> bool {
+    let column = match expr {
+        Expr::Column(c) => c,
+        _ => return false,
+    };
+    let (low, high) = match (as_literal_f64(low), as_literal_f64(high)) {
+        (Some(low), Some(high)) => (low, high),
+        _ => return false,
+    };
+
+    let column_index = match schema.index_of(column) {
+        Ok(i) => i,
+        Err(_) => return false,
+    };
+    let stats = match row_group.column(column_index).statistics() {
+        Some(s) if s.has_min_max_set() => s,
+        _ => return false,
+    };
+    let (min, max) = match stats_as_f64(stats) {
+        Some(bounds) => bounds,
+        None => return false,
+    };
+
+    max < low || min > high
+}
+
+fn as_literal_f64(expr: &Expr) -> Option<f64> {
+    match expr {
+        Expr::Literal(v) => as_f64(v),
+        _ => None,
+    }
+}
+
+/// Recognizes a single `column <op> literal` comparison, returning its parts
+/// in column-on-the-left form regardless of which side the column was on.
+fn as_column_comparison(expr: &Expr) -> Option<(String, Operator, ScalarValue)> {
+    if let Expr::BinaryExpr { left, op, right } = expr {
+        match (left.as_ref(), right.as_ref()) {
+            (Expr::Column(c), Expr::Literal(v)) => Some((c.clone(), *op, v.clone())),
+            (Expr::Literal(v), Expr::Column(c)) => Some((c.clone(), flip(*op), v.clone())),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+/// `v <op> col` is equivalent to `col <flip(op)> v`.
+fn flip(op: Operator) -> Operator {
+    match op {
+        Operator::Gt => Operator::Lt,
+        Operator::GtEq => Operator::LtEq,
+        Operator::Lt => Operator::Gt,
+        Operator::LtEq => Operator::GtEq,
+        other => other,
+    }
+}
+
+fn stats_as_f64(stats: &Statistics) -> Option<(f64, f64)> {
+    match stats {
+        Statistics::Int32(s) => Some((*s.min() as f64, *s.max() as f64)),
+        Statistics::Int64(s) => Some((*s.min() as f64, *s.max() as f64)),
+        Statistics::Float(s) => Some((*s.min() as f64, *s.max() as f64)),
+        Statistics::Double(s) => Some((*s.min(), *s.max())),
+        _ => None,
+    }
+}
+
+/// Returns `true` if a file carrying `partition_values` for
+/// `partition_columns` can be proven, from a `=`, `<`, `<=`, `>` or `>=`
+/// comparison between a partition column and a numeric literal, to contain
+/// no matching row.
+pub fn partition_prunes_file(
+    filters: &[Expr],
+    partition_columns: &[Field],
+    partition_values: &[ScalarValue],
+) -> bool {
+    filters.iter().any(|filter| {
+        let (column, op, literal) = match as_column_comparison(filter) {
+            Some(parts) => parts,
+            None => return false,
+        };
+        let index = match partition_columns.iter().position(|f| f.name() == &column) {
+            Some(i) => i,
+            None => return false,
+        };
+        let actual = &partition_values[index];
+        !satisfies(actual, op, &literal)
+    })
+}
+
+fn satisfies(actual: &ScalarValue, op: Operator, literal: &ScalarValue) -> bool {
+    let (a, v) = match (as_f64(actual), as_f64(literal)) {
+        (Some(a), Some(v)) => (a, v),
+        _ => return true, // can't compare; don't prune
+    };
+    match op {
+        Operator::Eq => a == v,
+        Operator::Gt => a > v,
+        Operator::GtEq => a >= v,
+        Operator::Lt => a < v,
+        Operator::LtEq => a <= v,
+        _ => true,
+    }
+}
+
+fn as_f64(value: &ScalarValue) -> Option<f64> {
+    match value {
+        ScalarValue::Int64(v) => Some(*v as f64),
+        ScalarValue::Float64(v) => Some(*v),
+        _ => None,
+    }
+}
+
+/// Splits a conjunction (`a AND b AND c`) into its individual conjuncts.
+/// Non-conjunction expressions are returned as a single-element vector.
+pub fn split_conjuncts(expr: &Expr) -> Vec<Expr> {
+    match expr {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And,
+            right,
+        } => {
+            let mut exprs = split_conjuncts(left);
+            exprs.extend(split_conjuncts(right));
+            exprs
+        }
+        other => vec![other.clone()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parquet::file::metadata::ColumnChunkMetaData;
+    use parquet::file::statistics::Statistics;
+    use parquet::schema::parser::parse_message_type;
+    use parquet::schema::types::SchemaDescriptor;
+    use std::sync::Arc;
+
+    fn row_group_with_id_stats(min: i64, max: i64) -> RowGroupMetaData {
+        let message = parse_message_type("message schema { REQUIRED INT64 id; }").unwrap();
+        let schema_descr = Arc::new(SchemaDescriptor::new(Arc::new(message)));
+        let column = ColumnChunkMetaData::builder(schema_descr.column(0))
+            .set_statistics(Statistics::int64(Some(min), Some(max), None, 0, false))
+            .build()
+            .unwrap();
+        RowGroupMetaData::builder(schema_descr)
+            .set_num_rows(4)
+            .set_column_metadata(vec![column])
+            .build()
+            .unwrap()
+    }
+
+    fn schema() -> Schema {
+        Schema::new(vec![arrow::datatypes::Field::new(
+            "id",
+            arrow::datatypes::DataType::Int64,
+            false,
+        )])
+    }
+
+    #[test]
+    fn not_eq_never_prunes() {
+        let row_group = row_group_with_id_stats(10, 20);
+        let filter = Expr::BinaryExpr {
+            left: Box::new(Expr::Column("id".to_string())),
+            op: Operator::NotEq,
+            right: Box::new(Expr::Literal(ScalarValue::Int64(15))),
+        };
+        assert!(!prunes_row_group(&[filter], &schema(), &row_group));
+    }
+
+    #[test]
+    fn between_prunes_row_group_outside_range() {
+        let row_group = row_group_with_id_stats(10, 20);
+        let filter = Expr::Between {
+            expr: Box::new(Expr::Column("id".to_string())),
+            low: Box::new(Expr::Literal(ScalarValue::Int64(100))),
+            high: Box::new(Expr::Literal(ScalarValue::Int64(200))),
+        };
+        assert!(prunes_row_group(&[filter], &schema(), &row_group));
+    }
+
+    #[test]
+    fn between_keeps_row_group_overlapping_range() {
+        let row_group = row_group_with_id_stats(10, 20);
+        let filter = Expr::Between {
+            expr: Box::new(Expr::Column("id".to_string())),
+            low: Box::new(Expr::Literal(ScalarValue::Int64(15))),
+            high: Box::new(Expr::Literal(ScalarValue::Int64(25))),
+        };
+        assert!(!prunes_row_group(&[filter], &schema(), &row_group));
+    }
+}
@@ -0,0 +1,74 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Turns an (optimized) `LogicalPlan` into an `ExecutionPlan`.
+
+use std::sync::Arc;
+
+use crate::error::{ExecutionError, Result};
+use crate::execution::physical_plan::filter::FilterExec;
+use crate::execution::physical_plan::pruning::split_conjuncts;
+use crate::execution::physical_plan::projection::ProjectionExec;
+use crate::execution::physical_plan::ExecutionPlan;
+use crate::logicalplan::{Expr, LogicalPlan};
+
+pub fn create_physical_plan(
+    plan: &LogicalPlan,
+    batch_size: usize,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    create_physical_plan_with_filters(plan, batch_size, &[])
+}
+
+/// Walks down through `Selection`/`Projection` nodes, accumulating the
+/// predicates found along the way, until it reaches the `TableScan` they
+/// apply to. The accumulated filters are passed to the scan so a data
+/// source (e.g. Parquet) can use them to prune whole row groups or files
+/// it doesn't need to read.
+///
+/// Pruning alone never discards an individual non-matching row (a kept row
+/// group can still contain rows that fail the predicate), and a
+/// `TableScan` never reorders or casts its columns. So on the way back up,
+/// `Selection` and `Projection` nodes are wrapped in `FilterExec` and
+/// `ProjectionExec` respectively, which is what actually enforces the
+/// predicate and produces the projected/cast output the logical plan
+/// promises.
+fn create_physical_plan_with_filters(
+    plan: &LogicalPlan,
+    batch_size: usize,
+    filters: &[Expr],
+) -> Result<Arc<dyn ExecutionPlan>> {
+    match plan {
+        LogicalPlan::TableScan {
+            table_provider,
+            projection,
+            ..
+        } => table_provider.scan(projection, batch_size, filters),
+        LogicalPlan::Selection { expr, input } => {
+            let mut pushed_down = filters.to_vec();
+            pushed_down.extend(split_conjuncts(expr));
+            let input_plan = create_physical_plan_with_filters(input, batch_size, &pushed_down)?;
+            Ok(Arc::new(FilterExec::new(input_plan, expr.clone())))
+        }
+        LogicalPlan::Projection { expr, input, schema } => {
+            let input_plan = create_physical_plan_with_filters(input, batch_size, filters)?;
+            Ok(Arc::new(ProjectionExec::new(input_plan, expr.clone(), schema.clone())))
+        }
+        LogicalPlan::CreateExternalTable { table_name, .. } => Err(ExecutionError::General(
+            format!("'{}' is a DDL statement and has no physical plan", table_name),
+        )),
+    }
+}
@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Logical query plan and expression types produced by the SQL frontend.
+
+use std::sync::Arc;
+
+use arrow::datatypes::{DataType, Schema};
+
+use crate::datasource::TableProvider;
+
+/// A scalar value that appears as a literal in an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScalarValue {
+    Null,
+    Boolean(bool),
+    Int64(i64),
+    Float64(f64),
+    Utf8(String),
+}
+
+/// An expression in a logical plan, e.g. part of a projection or filter.
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Column(String),
+    Literal(ScalarValue),
+    BinaryExpr {
+        left: Box<Expr>,
+        op: Operator,
+        right: Box<Expr>,
+    },
+    Cast {
+        expr: Box<Expr>,
+        data_type: DataType,
+    },
+    /// `expr BETWEEN low AND high`.
+    Between {
+        expr: Box<Expr>,
+        low: Box<Expr>,
+        high: Box<Expr>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    And,
+    Or,
+}
+
+/// A node in a logical query plan, mirroring the shape of the SQL statement
+/// that produced it.
+#[derive(Clone)]
+pub enum LogicalPlan {
+    /// Scan of a registered table, optionally with a set of projected columns.
+    TableScan {
+        table_name: String,
+        table_provider: Arc<dyn TableProvider>,
+        schema: Arc<Schema>,
+        projection: Option<Vec<usize>>,
+    },
+    /// Row selection based on a boolean predicate.
+    Selection { expr: Expr, input: Box<LogicalPlan> },
+    /// Column projection.
+    Projection {
+        expr: Vec<Expr>,
+        input: Box<LogicalPlan>,
+        schema: Arc<Schema>,
+    },
+    /// Declares an external table backed by Parquet files under a local
+    /// directory.
+    ///
+    /// Produced by `CREATE EXTERNAL TABLE ... STORED AS ... LOCATION ...`.
+    CreateExternalTable {
+        table_name: String,
+        location: String,
+        file_type: FileType,
+        partition_columns: Vec<(String, DataType)>,
+        schema: Arc<Schema>,
+        /// The table provider already constructed (and its files already
+        /// discovered) while planning this statement, so that registering
+        /// it doesn't require walking the source a second time.
+        table_provider: Arc<dyn TableProvider>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FileType {
+    Parquet,
+    Csv,
+}
+
+impl LogicalPlan {
+    pub fn schema(&self) -> &Arc<Schema> {
+        match self {
+            LogicalPlan::TableScan { schema, .. } => schema,
+            LogicalPlan::Selection { input, .. } => input.schema(),
+            LogicalPlan::Projection { schema, .. } => schema,
+            LogicalPlan::CreateExternalTable { schema, .. } => schema,
+        }
+    }
+}
+
+/// Whether `sql` is a DDL statement (`CREATE EXTERNAL TABLE`), which is
+/// handled outside the normal select-statement code path.
+pub fn is_ddl(sql: &str) -> bool {
+    sql.trim_start()
+        .to_uppercase()
+        .starts_with("CREATE EXTERNAL TABLE")
+}
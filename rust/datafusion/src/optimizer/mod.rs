@@ -0,0 +1,42 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Logical plan optimization passes.
+
+use crate::error::Result;
+use crate::logicalplan::LogicalPlan;
+
+/// Applies the registered optimization rules to a logical plan.
+pub struct Optimizer {}
+
+impl Optimizer {
+    pub fn new() -> Self {
+        Optimizer {}
+    }
+
+    pub fn optimize(&self, plan: &LogicalPlan) -> Result<LogicalPlan> {
+        // No rewrite rules yet; this is the extension point future passes
+        // (e.g. projection/predicate push-down) hang off of.
+        Ok(plan.clone())
+    }
+}
+
+impl Default for Optimizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
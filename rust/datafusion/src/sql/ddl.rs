@@ -0,0 +1,186 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parses `CREATE EXTERNAL TABLE` DDL, e.g.:
+//!
+//! ```sql
+//! CREATE EXTERNAL TABLE alltypes
+//! STORED AS PARQUET
+//! LOCATION '/data'
+//! PARTITIONED BY (year INT, month INT)
+//! ```
+
+use std::sync::Arc;
+
+use arrow::datatypes::DataType;
+
+use crate::datasource::partitioned_parquet::PartitionedParquetTable;
+use crate::datasource::TableProvider;
+use crate::error::{ExecutionError, Result};
+use crate::logicalplan::{FileType, LogicalPlan};
+
+pub fn parse_create_external_table(sql: &str) -> Result<LogicalPlan> {
+    let sql = sql.trim().trim_end_matches(';');
+    let rest = strip_prefix_ci(sql, "CREATE EXTERNAL TABLE")
+        .ok_or_else(|| ExecutionError::General("expected CREATE EXTERNAL TABLE".to_string()))?;
+
+    let stored_as_pos = find_ci(rest, "STORED AS")
+        .ok_or_else(|| ExecutionError::General("expected STORED AS clause".to_string()))?;
+    let table_name = rest[..stored_as_pos].trim().to_string();
+
+    let rest = &rest[stored_as_pos + "STORED AS".len()..];
+    let location_pos = find_ci(rest, "LOCATION")
+        .ok_or_else(|| ExecutionError::General("expected LOCATION clause".to_string()))?;
+    let file_type = match rest[..location_pos].trim().to_uppercase().as_str() {
+        "PARQUET" => FileType::Parquet,
+        "CSV" => FileType::Csv,
+        other => {
+            return Err(ExecutionError::NotImplemented(format!(
+                "unsupported STORED AS file type: {}",
+                other
+            )))
+        }
+    };
+
+    let rest = &rest[location_pos + "LOCATION".len()..];
+    let partitioned_by_pos = find_ci(rest, "PARTITIONED BY");
+    let (location_part, partition_part) = match partitioned_by_pos {
+        Some(pos) => (&rest[..pos], Some(&rest[pos + "PARTITIONED BY".len()..])),
+        None => (rest, None),
+    };
+    let location = parse_quoted_string(location_part.trim())?;
+
+    let partition_columns = match partition_part {
+        Some(p) => parse_partition_columns(p.trim())?,
+        None => vec![],
+    };
+
+    // Discover the files and infer the schema once, here, so a malformed
+    // DDL statement (bad location, missing partition directories) fails
+    // fast at plan time. The resulting table is carried on the logical
+    // plan and registered as-is, rather than being rebuilt (and the
+    // directory tree re-walked) when the statement is executed.
+    let table = Arc::new(PartitionedParquetTable::try_new(
+        &location,
+        partition_columns.clone(),
+    )?);
+    let schema = table.schema();
+
+    Ok(LogicalPlan::CreateExternalTable {
+        table_name,
+        location,
+        file_type,
+        partition_columns,
+        schema,
+        table_provider: table,
+    })
+}
+
+fn strip_prefix_ci<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    if s.len() >= prefix.len() && s[..prefix.len()].eq_ignore_ascii_case(prefix) {
+        Some(&s[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+fn find_ci(s: &str, needle: &str) -> Option<usize> {
+    s.to_uppercase().find(&needle.to_uppercase())
+}
+
+fn parse_quoted_string(s: &str) -> Result<String> {
+    let s = s.trim();
+    if s.len() >= 2 && s.starts_with('\'') && s.ends_with('\'') {
+        Ok(s[1..s.len() - 1].to_string())
+    } else {
+        Err(ExecutionError::General(format!(
+            "expected a quoted string, found '{}'",
+            s
+        )))
+    }
+}
+
+/// Parses `(year INT, month INT)` into `[("year", Int32), ("month", Int32)]`.
+fn parse_partition_columns(s: &str) -> Result<Vec<(String, DataType)>> {
+    let s = s.trim();
+    let s = s
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .ok_or_else(|| {
+            ExecutionError::General("expected parenthesized partition column list".to_string())
+        })?;
+    s.split(',')
+        .map(|col| {
+            let mut parts = col.split_whitespace();
+            let name = parts
+                .next()
+                .ok_or_else(|| ExecutionError::General("expected a partition column name".to_string()))?;
+            let type_name = parts
+                .next()
+                .ok_or_else(|| ExecutionError::General("expected a partition column type".to_string()))?;
+            Ok((name.to_string(), parse_data_type(type_name)?))
+        })
+        .collect()
+}
+
+fn parse_data_type(name: &str) -> Result<DataType> {
+    match name.to_uppercase().as_str() {
+        "INT" | "INTEGER" => Ok(DataType::Int32),
+        "BIGINT" => Ok(DataType::Int64),
+        "DOUBLE" | "FLOAT" => Ok(DataType::Float64),
+        "VARCHAR" | "TEXT" | "STRING" => Ok(DataType::Utf8),
+        other => Err(ExecutionError::NotImplemented(format!(
+            "unsupported partition column type: {}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strip_prefix_ci_is_case_insensitive() {
+        assert_eq!(strip_prefix_ci("create external table t", "CREATE EXTERNAL TABLE"), Some(" t"));
+        assert_eq!(strip_prefix_ci("SELECT 1", "CREATE EXTERNAL TABLE"), None);
+    }
+
+    #[test]
+    fn parse_quoted_string_requires_quotes() {
+        assert_eq!(parse_quoted_string("'/data'").unwrap(), "/data");
+        assert!(parse_quoted_string("/data").is_err());
+    }
+
+    #[test]
+    fn parse_partition_columns_parses_name_and_type() {
+        let columns = parse_partition_columns("(year INT, month INT)").unwrap();
+        assert_eq!(
+            columns,
+            vec![
+                ("year".to_string(), DataType::Int32),
+                ("month".to_string(), DataType::Int32),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_partition_columns_rejects_missing_parens() {
+        assert!(parse_partition_columns("year INT").is_err());
+    }
+}
+
@@ -0,0 +1,333 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Converts a SQL statement into a `LogicalPlan`.
+//!
+//! This is a small, purpose-built front end (not a general SQL grammar): it
+//! covers `SELECT <expr, ...> FROM <table> [WHERE <predicate>]` and, for DDL,
+//! `CREATE EXTERNAL TABLE`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::datatypes::{DataType, Field, Schema};
+
+use crate::datasource::TableProvider;
+use crate::error::{ExecutionError, Result};
+use crate::logicalplan::{Expr, LogicalPlan, Operator, ScalarValue};
+
+pub struct SqlToRel<'a> {
+    datasources: &'a HashMap<String, Arc<dyn TableProvider>>,
+}
+
+impl<'a> SqlToRel<'a> {
+    pub fn new(datasources: &'a HashMap<String, Arc<dyn TableProvider>>) -> Self {
+        SqlToRel { datasources }
+    }
+
+    pub fn statement_to_plan(&self, sql: &str) -> Result<LogicalPlan> {
+        let trimmed = sql.trim().trim_end_matches(';');
+        if trimmed.to_uppercase().starts_with("CREATE EXTERNAL TABLE") {
+            return crate::sql::ddl::parse_create_external_table(trimmed);
+        }
+        self.select_to_plan(trimmed)
+    }
+
+    fn select_to_plan(&self, sql: &str) -> Result<LogicalPlan> {
+        let upper = sql.to_uppercase();
+        if !upper.starts_with("SELECT ") {
+            return Err(ExecutionError::NotImplemented(format!(
+                "unsupported statement: {}",
+                sql
+            )));
+        }
+
+        let from_pos = find_keyword(&upper, " FROM ")
+            .ok_or_else(|| ExecutionError::General("expected FROM clause".to_string()))?;
+        let select_list = &sql[7..from_pos];
+
+        let rest = &sql[from_pos + 6..];
+        let (table_name, where_clause) = match find_keyword(&rest.to_uppercase(), " WHERE ") {
+            Some(where_pos) => (rest[..where_pos].trim(), Some(rest[where_pos + 7..].trim())),
+            None => (rest.trim(), None),
+        };
+
+        let provider = self
+            .datasources
+            .get(table_name)
+            .ok_or_else(|| ExecutionError::General(format!("no table named '{}'", table_name)))?
+            .clone();
+        let table_schema = provider.schema();
+
+        let mut plan = LogicalPlan::TableScan {
+            table_name: table_name.to_string(),
+            table_provider: provider,
+            schema: table_schema.clone(),
+            projection: None,
+        };
+
+        if let Some(predicate) = where_clause {
+            let expr = parse_predicate(predicate)?;
+            plan = LogicalPlan::Selection {
+                expr,
+                input: Box::new(plan),
+            };
+        }
+
+        let exprs = split_top_level(select_list, ',')
+            .into_iter()
+            .map(|e| parse_select_expr(e.trim()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let fields = exprs
+            .iter()
+            .map(|e| resolve_field(e, &table_schema))
+            .collect::<Result<Vec<_>>>()?;
+        let projected_schema = Arc::new(Schema::new(fields));
+
+        Ok(LogicalPlan::Projection {
+            expr: exprs,
+            input: Box::new(plan),
+            schema: projected_schema,
+        })
+    }
+}
+
+fn find_keyword(upper: &str, keyword: &str) -> Option<usize> {
+    upper.find(keyword)
+}
+
+fn split_top_level(s: &str, sep: char) -> Vec<&str> {
+    let mut depth = 0i32;
+    let mut parts = vec![];
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            c if c == sep && depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+fn parse_select_expr(e: &str) -> Result<Expr> {
+    let upper = e.to_uppercase();
+    if upper.starts_with("CAST(") && upper.ends_with(')') {
+        let inner = &e[5..e.len() - 1];
+        let as_pos = inner
+            .to_uppercase()
+            .find(" AS ")
+            .ok_or_else(|| ExecutionError::General(format!("malformed CAST: {}", e)))?;
+        let column = inner[..as_pos].trim();
+        let type_name = inner[as_pos + 4..].trim();
+        return Ok(Expr::Cast {
+            expr: Box::new(Expr::Column(column.to_string())),
+            data_type: parse_data_type(type_name)?,
+        });
+    }
+    Ok(Expr::Column(e.to_string()))
+}
+
+fn parse_data_type(name: &str) -> Result<DataType> {
+    match name.to_uppercase().as_str() {
+        "VARCHAR" | "TEXT" | "STRING" => Ok(DataType::Utf8),
+        "INT" | "INTEGER" => Ok(DataType::Int32),
+        "BIGINT" => Ok(DataType::Int64),
+        "DOUBLE" | "FLOAT" => Ok(DataType::Float64),
+        other => Err(ExecutionError::NotImplemented(format!(
+            "unsupported CAST target type: {}",
+            other
+        ))),
+    }
+}
+
+fn resolve_field(expr: &Expr, table_schema: &Arc<Schema>) -> Result<Field> {
+    match expr {
+        Expr::Column(name) => table_schema
+            .field_with_name(name)
+            .cloned()
+            .map_err(|e| ExecutionError::General(e.to_string())),
+        Expr::Cast { expr, data_type } => {
+            let input_field = resolve_field(expr, table_schema)?;
+            Ok(Field::new(input_field.name(), data_type.clone(), input_field.is_nullable()))
+        }
+        _ => Err(ExecutionError::NotImplemented(
+            "unsupported projection expression".to_string(),
+        )),
+    }
+}
+
+/// Parses a conjunction of simple comparisons, e.g. `id > 1 AND tinyint_col < double_col`.
+pub(crate) fn parse_predicate(predicate: &str) -> Result<Expr> {
+    let mut exprs = split_top_level_and(predicate)
+        .iter()
+        .map(|p| parse_comparison(p.trim()))
+        .collect::<Result<Vec<_>>>()?;
+    let mut expr = exprs.remove(0);
+    for right in exprs {
+        expr = Expr::BinaryExpr {
+            left: Box::new(expr),
+            op: Operator::And,
+            right: Box::new(right),
+        };
+    }
+    Ok(expr)
+}
+
+/// Splits a conjunction on its top-level " AND "s. A `BETWEEN low AND high`
+/// is not itself a conjunction, so the " AND " that pairs with a preceding
+/// "BETWEEN" is not treated as a split point, e.g. `a BETWEEN 1 AND 2 AND b
+/// > 0` splits into `a BETWEEN 1 AND 2` and `b > 0`, not three parts.
+fn split_top_level_and(s: &str) -> Vec<&str> {
+    let upper = s.to_uppercase();
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut search_from = 0;
+    let mut pending_between = false;
+    loop {
+        let next_and = upper[search_from..].find(" AND ").map(|p| search_from + p);
+        let next_between = upper[search_from..].find(" BETWEEN ").map(|p| search_from + p);
+        let and_pos = match next_and {
+            Some(pos) => pos,
+            None => break,
+        };
+        if let Some(between_pos) = next_between {
+            if between_pos < and_pos && !pending_between {
+                pending_between = true;
+                search_from = between_pos + " BETWEEN ".len();
+                continue;
+            }
+        }
+        if pending_between {
+            pending_between = false;
+        } else {
+            parts.push(&s[start..and_pos]);
+            start = and_pos + " AND ".len();
+        }
+        search_from = and_pos + " AND ".len();
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+fn parse_comparison(s: &str) -> Result<Expr> {
+    let upper = s.to_uppercase();
+    if let Some(between_pos) = upper.find(" BETWEEN ") {
+        let and_pos = upper[between_pos..]
+            .find(" AND ")
+            .map(|p| between_pos + p)
+            .ok_or_else(|| ExecutionError::General(format!("malformed BETWEEN: {}", s)))?;
+        let column = s[..between_pos].trim();
+        let low = s[between_pos + " BETWEEN ".len()..and_pos].trim();
+        let high = s[and_pos + " AND ".len()..].trim();
+        return Ok(Expr::Between {
+            expr: Box::new(parse_operand(column)),
+            low: Box::new(parse_operand(low)),
+            high: Box::new(parse_operand(high)),
+        });
+    }
+
+    for (token, op) in [
+        (">=", Operator::GtEq),
+        ("<=", Operator::LtEq),
+        ("!=", Operator::NotEq),
+        (">", Operator::Gt),
+        ("<", Operator::Lt),
+        ("=", Operator::Eq),
+    ] {
+        if let Some(pos) = s.find(token) {
+            let left = s[..pos].trim();
+            let right = s[pos + token.len()..].trim();
+            return Ok(Expr::BinaryExpr {
+                left: Box::new(parse_operand(left)),
+                op,
+                right: Box::new(parse_operand(right)),
+            });
+        }
+    }
+    Err(ExecutionError::General(format!(
+        "unsupported predicate: {}",
+        s
+    )))
+}
+
+fn parse_operand(s: &str) -> Expr {
+    if let Ok(i) = s.parse::<i64>() {
+        Expr::Literal(ScalarValue::Int64(i))
+    } else if let Ok(f) = s.parse::<f64>() {
+        Expr::Literal(ScalarValue::Float64(f))
+    } else {
+        Expr::Column(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_comparison_maps_not_eq_to_not_eq_operator() {
+        let expr = parse_comparison("id != 1").unwrap();
+        match expr {
+            Expr::BinaryExpr { op, .. } => assert_eq!(op, Operator::NotEq),
+            other => panic!("expected a BinaryExpr, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn split_top_level_and_splits_plain_conjunction() {
+        let parts = split_top_level_and("id > 1 AND tinyint_col < double_col");
+        assert_eq!(parts, vec!["id > 1", "tinyint_col < double_col"]);
+    }
+
+    #[test]
+    fn parse_comparison_parses_between() {
+        let expr = parse_comparison("id BETWEEN 1 AND 10").unwrap();
+        match expr {
+            Expr::Between { expr, low, high } => {
+                assert!(matches!(*expr, Expr::Column(ref c) if c == "id"));
+                assert!(matches!(*low, Expr::Literal(ScalarValue::Int64(1))));
+                assert!(matches!(*high, Expr::Literal(ScalarValue::Int64(10))));
+            }
+            other => panic!("expected a Between, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn split_top_level_and_keeps_between_and_together() {
+        let parts = split_top_level_and("id BETWEEN 1 AND 10 AND tinyint_col < 5");
+        assert_eq!(parts, vec!["id BETWEEN 1 AND 10", "tinyint_col < 5"]);
+    }
+
+    #[test]
+    fn parse_predicate_builds_between_conjunction() {
+        let expr = parse_predicate("id BETWEEN 1 AND 10 AND tinyint_col < 5").unwrap();
+        match expr {
+            Expr::BinaryExpr { left, op: Operator::And, right } => {
+                assert!(matches!(*left, Expr::Between { .. }));
+                assert!(matches!(*right, Expr::BinaryExpr { .. }));
+            }
+            other => panic!("expected a top-level And, got {:?}", other),
+        }
+    }
+}